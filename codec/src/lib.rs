@@ -0,0 +1,129 @@
+//! 消费端使用的 Pubkey / 签名编解码工具
+//!
+//! 模块以 `pubkey_encoding=raw` 运行时，`SwapEvent` 的 `*_raw` 字段为原始字节，
+//! 消费端只在需要展示或拼接时再转为 Base58。
+
+use thiserror::Error;
+
+/// Pubkey 字节长度
+pub const PUBKEY_LEN: usize = 32;
+
+/// 交易签名字节长度
+pub const SIGNATURE_LEN: usize = 64;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CodecError {
+    #[error("invalid base58: {0}")]
+    InvalidBase58(String),
+    #[error("invalid length: expected {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+}
+
+// ============================================================================
+// 字节 -> Base58
+// ============================================================================
+
+/// 原始 Pubkey 转 Base58，空字节 (占位事件) 返回空字符串
+pub fn pubkey_to_string(raw: &[u8]) -> Result<String, CodecError> {
+    encode_fixed(raw, PUBKEY_LEN)
+}
+
+/// 原始签名转 Base58
+pub fn signature_to_string(raw: &[u8]) -> Result<String, CodecError> {
+    encode_fixed(raw, SIGNATURE_LEN)
+}
+
+fn encode_fixed(raw: &[u8], expected: usize) -> Result<String, CodecError> {
+    if raw.is_empty() {
+        return Ok(String::new());
+    }
+    check_len(raw.len(), expected)?;
+    Ok(bs58::encode(raw).into_string())
+}
+
+// ============================================================================
+// Base58 -> 字节
+// ============================================================================
+
+/// Base58 Pubkey 转 32 字节
+pub fn pubkey_from_str(s: &str) -> Result<[u8; PUBKEY_LEN], CodecError> {
+    decode_fixed(s)
+}
+
+/// Base58 签名转 64 字节
+pub fn signature_from_str(s: &str) -> Result<[u8; SIGNATURE_LEN], CodecError> {
+    decode_fixed(s)
+}
+
+fn decode_fixed<const N: usize>(s: &str) -> Result<[u8; N], CodecError> {
+    let bytes = bs58::decode(s)
+        .into_vec()
+        .map_err(|e| CodecError::InvalidBase58(e.to_string()))?;
+    check_len(bytes.len(), N)?;
+    let mut out = [0u8; N];
+    out.copy_from_slice(&bytes);
+    Ok(out)
+}
+
+fn check_len(actual: usize, expected: usize) -> Result<(), CodecError> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(CodecError::InvalidLength { expected, actual })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WSOL: &str = "So11111111111111111111111111111111111111112";
+
+    #[test]
+    fn pubkey_round_trip() {
+        let raw = pubkey_from_str(WSOL).unwrap();
+        assert_eq!(pubkey_to_string(&raw).unwrap(), WSOL);
+    }
+
+    #[test]
+    fn signature_round_trip() {
+        let raw = [7u8; SIGNATURE_LEN];
+        let encoded = signature_to_string(&raw).unwrap();
+        assert_eq!(signature_from_str(&encoded).unwrap(), raw);
+    }
+
+    #[test]
+    fn empty_bytes_encode_to_empty_string() {
+        assert_eq!(pubkey_to_string(&[]).unwrap(), "");
+        assert_eq!(signature_to_string(&[]).unwrap(), "");
+    }
+
+    #[test]
+    fn rejects_wrong_length() {
+        assert_eq!(
+            pubkey_to_string(&[1u8; 31]),
+            Err(CodecError::InvalidLength {
+                expected: PUBKEY_LEN,
+                actual: 31
+            })
+        );
+        // 64 字节的签名不是合法的 Pubkey
+        let signature = signature_to_string(&[7u8; SIGNATURE_LEN]).unwrap();
+        assert!(matches!(
+            pubkey_from_str(&signature),
+            Err(CodecError::InvalidLength {
+                expected: PUBKEY_LEN,
+                actual: SIGNATURE_LEN
+            })
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_base58() {
+        // 0 / O / I / l 不在 Base58 字母表中
+        assert!(matches!(
+            pubkey_from_str("0OIl"),
+            Err(CodecError::InvalidBase58(_))
+        ));
+    }
+}
@@ -0,0 +1,52 @@
+use substreams_solana::pb::sf::solana::r#type::v1::{Message, TransactionStatusMeta};
+
+/// 交易完整账户列表的借用视图 (不复制任何地址)
+/// 顺序与 Solana 一致: 静态账户 -> ALT 可写地址 -> ALT 只读地址
+#[derive(Clone, Copy)]
+pub struct AccountKeys<'a> {
+    static_keys: &'a [Vec<u8>],
+    loaded_writable: &'a [Vec<u8>],
+    loaded_readonly: &'a [Vec<u8>],
+    /// v0 交易引用了 ALT，但 meta 中没有加载后的地址
+    lookups_unresolved: bool,
+}
+
+impl<'a> AccountKeys<'a> {
+    pub fn new(message: &'a Message, meta: &'a TransactionStatusMeta) -> Self {
+        Self {
+            static_keys: &message.account_keys,
+            loaded_writable: &meta.loaded_writable_addresses,
+            loaded_readonly: &meta.loaded_readonly_addresses,
+            lookups_unresolved: !message.address_table_lookups.is_empty()
+                && meta.loaded_writable_addresses.is_empty()
+                && meta.loaded_readonly_addresses.is_empty(),
+        }
+    }
+
+    /// 按账户索引取地址，越界返回 None
+    pub fn get(&self, index: usize) -> Option<&'a [u8]> {
+        let mut index = index;
+        for segment in [self.static_keys, self.loaded_writable, self.loaded_readonly] {
+            if index < segment.len() {
+                return Some(&segment[index]);
+            }
+            index -= segment.len();
+        }
+        None
+    }
+
+    /// 账户总数
+    pub fn len(&self) -> usize {
+        self.static_keys.len() + self.loaded_writable.len() + self.loaded_readonly.len()
+    }
+
+    /// 没有任何账户
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// ALT 地址是否缺失 (越界的索引应归因于 ALT 而不是指令本身)
+    pub fn lookups_unresolved(&self) -> bool {
+        self.lookups_unresolved
+    }
+}
@@ -0,0 +1,58 @@
+use substreams_solana::pb::sf::solana::r#type::v1::{TokenBalance, TransactionStatusMeta};
+
+/// Token Balance 的原始数量，缺失或无法解析时为 0
+pub fn token_amount(balance: Option<&TokenBalance>) -> i128 {
+    balance
+        .and_then(|b| b.ui_token_amount.as_ref())
+        .and_then(|amt| amt.amount.parse().ok())
+        .unwrap_or(0)
+}
+
+/// Token 账户的 (mint, 精度)，取自交易前后的余额记录 (交易后优先)
+pub fn account_mint(meta: &TransactionStatusMeta, account_index: u32) -> Option<(String, u32)> {
+    meta.post_token_balances
+        .iter()
+        .chain(meta.pre_token_balances.iter())
+        .find(|b| b.account_index == account_index)
+        .map(|b| {
+            let decimals = b.ui_token_amount.as_ref().map(|a| a.decimals).unwrap_or(0);
+            (b.mint.clone(), decimals)
+        })
+}
+
+/// Token 账户的 owner，取自交易前后的余额记录 (交易前优先)；旧区块没有 owner 时返回 None
+pub fn account_owner(meta: &TransactionStatusMeta, account_index: u32) -> Option<&str> {
+    meta.pre_token_balances
+        .iter()
+        .chain(meta.post_token_balances.iter())
+        .find(|b| b.account_index == account_index && !b.owner.is_empty())
+        .map(|b| b.owner.as_str())
+}
+
+/// owner 持有的某个 mint 在交易前后的余额变化合计 (i128 计算，不会溢出)
+/// 交易前后都没有对应的余额记录时返回 None
+pub fn owner_token_change(meta: &TransactionStatusMeta, owner: &str, mint: &str) -> Option<i128> {
+    let matches = |b: &&TokenBalance| b.owner == owner && b.mint == mint;
+    let mut found = false;
+    let mut change: i128 = 0;
+
+    for balance in meta.post_token_balances.iter().filter(matches) {
+        found = true;
+        change += token_amount(Some(balance));
+    }
+    for balance in meta.pre_token_balances.iter().filter(matches) {
+        found = true;
+        change -= token_amount(Some(balance));
+    }
+
+    found.then_some(change)
+}
+
+/// Token 账户在交易前的原始余额，没有余额记录时为 0
+pub fn pre_token_amount(meta: &TransactionStatusMeta, account_index: u32) -> u64 {
+    let balance = meta
+        .pre_token_balances
+        .iter()
+        .find(|b| b.account_index == account_index);
+    u64::try_from(token_amount(balance)).unwrap_or(0)
+}
@@ -0,0 +1,60 @@
+use substreams_solana::b58;
+use substreams_solana_utils::pubkey::Pubkey;
+
+// ============================================================================
+// System / Token Program IDs (用于识别 Swap 内部的转账与临时 WSOL 账户的资金流)
+// ============================================================================
+
+/// System Program
+pub const SYSTEM_PROGRAM_ID: Pubkey = Pubkey(b58!("11111111111111111111111111111111"));
+
+/// SPL Token
+pub const TOKEN_PROGRAM_ID: Pubkey = Pubkey(b58!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"));
+
+/// Token-2022
+pub const TOKEN_2022_PROGRAM_ID: Pubkey =
+    Pubkey(b58!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"));
+
+// ============================================================================
+// Mints
+// ============================================================================
+
+/// WSOL (原生 SOL 以临时 WSOL 账户结算时不出现在 token balances 中)
+pub const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+/// USDC
+pub const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+/// USDT
+pub const USDT_MINT: &str = "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB";
+
+/// 作为计价币的 Mint 列表 (优先级从高到低)，用于确定每个池子的 base / quote
+pub const QUOTE_MINTS: &[&str] = &[USDC_MINT, USDT_MINT, WSOL_MINT];
+
+// ============================================================================
+// Compute Budget / Jito (用于统计交易的优先费与小费)
+// ============================================================================
+
+/// Compute Budget Program
+pub const COMPUTE_BUDGET_PROGRAM_ID: Pubkey =
+    Pubkey(b58!("ComputeBudget111111111111111111111111111111"));
+
+/// Compute Budget 指令: SetComputeUnitLimit (tag + u32 units)
+pub const COMPUTE_BUDGET_SET_UNIT_LIMIT: u8 = 2;
+
+/// Compute Budget 指令: SetComputeUnitPrice (tag + u64 micro-lamports / CU)
+pub const COMPUTE_BUDGET_SET_UNIT_PRICE: u8 = 3;
+
+/// 每个签名的基础手续费 (lamports)
+pub const LAMPORTS_PER_SIGNATURE: u64 = 5000;
+
+/// Jito tip 账户 (向其转入 SOL 即为 bundle 小费)
+pub const JITO_TIP_ACCOUNTS: &[Pubkey] = &[
+    Pubkey(b58!("96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5")),
+    Pubkey(b58!("HFqU5x63VTqvQss8hp11i4wVV8bD44PvwucfZ2bU7gRe")),
+    Pubkey(b58!("Cw8CFyM9FkoMi7K7Crf6HNQqf4uEMzpKw6QNghXLvLkY")),
+    Pubkey(b58!("ADaUMid9yfUytqMBgopwjb2DTLSokTSzL1zt6iGPaS49")),
+    Pubkey(b58!("DfXygSm4jCyNCybVYYK6DwvWqjKee8pbDmJGcLWNDXjh")),
+    Pubkey(b58!("ADuUkR4vqLUMWXxW9gh6D6L8pMSawimctcNZ5pGwDcEt")),
+    Pubkey(b58!("DttWaMuVvTiduZRnguLF7jNxTgiMBZ1hyAumKUiL2KRL")),
+];
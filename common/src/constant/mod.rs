@@ -0,0 +1,19 @@
+pub mod constant;
+
+pub use constant::{
+    // Compute Budget / Jito
+    COMPUTE_BUDGET_PROGRAM_ID,
+    COMPUTE_BUDGET_SET_UNIT_LIMIT,
+    COMPUTE_BUDGET_SET_UNIT_PRICE,
+    JITO_TIP_ACCOUNTS,
+    LAMPORTS_PER_SIGNATURE,
+    // Mints
+    QUOTE_MINTS,
+    // System / Token Programs
+    SYSTEM_PROGRAM_ID,
+    TOKEN_2022_PROGRAM_ID,
+    TOKEN_PROGRAM_ID,
+    USDC_MINT,
+    USDT_MINT,
+    WSOL_MINT,
+};
@@ -0,0 +1,89 @@
+use crate::account::AccountKeys;
+use crate::constant::{
+    COMPUTE_BUDGET_PROGRAM_ID, COMPUTE_BUDGET_SET_UNIT_LIMIT, COMPUTE_BUDGET_SET_UNIT_PRICE,
+    JITO_TIP_ACCOUNTS, LAMPORTS_PER_SIGNATURE, SYSTEM_PROGRAM_ID,
+};
+use crate::instruction::FlattenedInstruction;
+use crate::system::parse_system_transfer;
+use substreams_solana::pb::sf::solana::r#type::v1::TransactionStatusMeta;
+
+/// 交易级执行成本，同一交易内的所有 Swap 事件共享
+#[derive(Default)]
+pub struct TransactionCost {
+    /// SetComputeUnitLimit 请求的 CU 上限，未设置时为 0
+    pub compute_unit_limit: u32,
+    /// SetComputeUnitPrice 设置的 CU 单价 (micro-lamports)，未设置时为 0
+    pub compute_unit_price: u64,
+    /// 实际消耗的 CU，旧区块缺失时为 0
+    pub compute_units_consumed: u64,
+    /// 交易总手续费 (meta.fee = base_fee + priority_fee)
+    pub fee: u64,
+    /// 签名费: 5000 lamports * 签名数
+    pub base_fee: u64,
+    /// 优先费: fee - base_fee
+    pub priority_fee: u64,
+    /// 转入 Jito tip 账户的 SOL 合计 (lamports)
+    pub jito_tip: u64,
+}
+
+/// 解析交易的 Compute Budget 设置、手续费构成与 Jito 小费
+pub fn transaction_cost(
+    meta: &TransactionStatusMeta,
+    account_keys: &AccountKeys,
+    instructions: &[FlattenedInstruction],
+    signature_count: usize,
+) -> TransactionCost {
+    let base_fee = LAMPORTS_PER_SIGNATURE.saturating_mul(signature_count as u64);
+    let mut cost = TransactionCost {
+        compute_units_consumed: meta.compute_units_consumed.unwrap_or(0),
+        fee: meta.fee,
+        base_fee,
+        priority_fee: meta.fee.saturating_sub(base_fee),
+        ..Default::default()
+    };
+
+    for inst in instructions {
+        let program_id = match account_keys.get(inst.program_id_index as usize) {
+            Some(id) => id,
+            None => continue,
+        };
+
+        // Compute Budget 指令只在外层生效 (不可 CPI 调用)
+        if program_id == COMPUTE_BUDGET_PROGRAM_ID.0.as_slice() && inst.inner_instruction_index == 0
+        {
+            match inst.data.first() {
+                Some(&COMPUTE_BUDGET_SET_UNIT_LIMIT) => {
+                    if let Some(units) = inst.data.get(1..5).and_then(|b| b.try_into().ok()) {
+                        cost.compute_unit_limit = u32::from_le_bytes(units);
+                    }
+                }
+                Some(&COMPUTE_BUDGET_SET_UNIT_PRICE) => {
+                    if let Some(price) = inst.data.get(1..9).and_then(|b| b.try_into().ok()) {
+                        cost.compute_unit_price = u64::from_le_bytes(price);
+                    }
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        // Jito 小费: 外层或 CPI 中转入 tip 账户的 SOL
+        if program_id != SYSTEM_PROGRAM_ID.0.as_slice() {
+            continue;
+        }
+        let lamports = match parse_system_transfer(inst.data) {
+            Some(lamports) => lamports,
+            None => continue,
+        };
+        let is_tip = inst
+            .accounts
+            .get(1)
+            .and_then(|&index| account_keys.get(index as usize))
+            .is_some_and(|to| JITO_TIP_ACCOUNTS.iter().any(|tip| tip.0.as_slice() == to));
+        if is_tip {
+            cost.jito_tip = cost.jito_tip.saturating_add(lamports);
+        }
+    }
+
+    cost
+}
@@ -0,0 +1,77 @@
+use substreams_solana::pb::sf::solana::r#type::v1::{Message, TransactionStatusMeta};
+
+/// 展开后的单条指令 (外层或内层)，附带其在交易中的指令路径
+pub struct FlattenedInstruction<'a> {
+    /// 外层指令序号
+    pub instruction_index: u32,
+    /// 内层指令序号 (从 1 开始，0 表示外层指令本身)
+    pub inner_instruction_index: u32,
+    /// CPI 深度 (外层指令为 1，旧区块的内层指令可能缺失)
+    pub stack_height: Option<u32>,
+    pub program_id_index: u32,
+    pub accounts: &'a [u8],
+    pub data: &'a [u8],
+}
+
+/// 按执行顺序展开交易中的所有指令: 外层指令后紧跟其内层指令 (CPI)
+pub fn flatten_instructions<'a>(
+    message: &'a Message,
+    meta: &'a TransactionStatusMeta,
+) -> Vec<FlattenedInstruction<'a>> {
+    let mut instructions = Vec::new();
+
+    for (index, outer) in message.instructions.iter().enumerate() {
+        let instruction_index = index as u32;
+        instructions.push(FlattenedInstruction {
+            instruction_index,
+            inner_instruction_index: 0,
+            stack_height: Some(1),
+            program_id_index: outer.program_id_index,
+            accounts: &outer.accounts,
+            data: &outer.data,
+        });
+
+        let inners = meta
+            .inner_instructions
+            .iter()
+            .filter(|inner| inner.index == instruction_index)
+            .flat_map(|inner| inner.instructions.iter());
+
+        for (inner_index, inner) in inners.enumerate() {
+            instructions.push(FlattenedInstruction {
+                instruction_index,
+                inner_instruction_index: inner_index as u32 + 1,
+                stack_height: inner.stack_height,
+                program_id_index: inner.program_id_index,
+                accounts: &inner.accounts,
+                data: &inner.data,
+            });
+        }
+    }
+
+    instructions
+}
+
+/// 获取第 position 条指令发起的所有子指令 (CPI)
+/// 缺失 stack_height 时，退化为同一外层指令下后续的全部内层指令
+pub fn get_child_instructions<'a, 'b>(
+    instructions: &'b [FlattenedInstruction<'a>],
+    position: usize,
+) -> &'b [FlattenedInstruction<'a>] {
+    let parent = &instructions[position];
+    let start = position + 1;
+
+    let end = instructions[start..]
+        .iter()
+        .position(|inst| {
+            inst.instruction_index != parent.instruction_index
+                || matches!(
+                    (inst.stack_height, parent.stack_height),
+                    (Some(height), Some(parent_height)) if height <= parent_height
+                )
+        })
+        .map(|offset| start + offset)
+        .unwrap_or(instructions.len());
+
+    &instructions[start..end]
+}
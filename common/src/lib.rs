@@ -0,0 +1,13 @@
+//! 各协议解析 crate 共用的指令展开、Token 转账、余额查询、模块参数与交易成本解析
+
+pub mod account;
+pub mod balance;
+pub mod constant;
+pub mod cost;
+pub mod instruction;
+pub mod params;
+pub mod price;
+pub mod quote;
+pub mod swap;
+pub mod system;
+pub mod transfer;
@@ -0,0 +1,94 @@
+use dex_codec::{pubkey_from_str, CodecError};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParamError {
+    #[error("invalid param `{0}`, expected key=value")]
+    MissingValue(String),
+    #[error("invalid pubkey `{value}`: {source}")]
+    InvalidPubkey { value: String, source: CodecError },
+}
+
+/// 拆分 URL query 格式的模块参数: `key=value&key2=value2`
+/// 空片段忽略，键与值去掉首尾空白；是否接受某个键由调用方决定
+pub fn query_pairs(params: &str) -> Result<Vec<(&str, &str)>, ParamError> {
+    params
+        .split('&')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            pair.split_once('=')
+                .map(|(key, value)| (key.trim(), value.trim()))
+                .ok_or_else(|| ParamError::MissingValue(pair.to_string()))
+        })
+        .collect()
+}
+
+/// 解析逗号分隔的 Base58 Pubkey 列表，每个地址都必须能解码为 32 字节
+pub fn pubkey_list(value: &str) -> Result<Vec<String>, ParamError> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|pubkey| !pubkey.is_empty())
+        .map(|pubkey| {
+            pubkey_from_str(pubkey)
+                .map(|_| pubkey.to_string())
+                .map_err(|source| ParamError::InvalidPubkey {
+                    value: pubkey.to_string(),
+                    source,
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WSOL: &str = "So11111111111111111111111111111111111111112";
+    const USDC: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+    #[test]
+    fn query_pairs_trims_and_skips_empty_segments() {
+        assert_eq!(query_pairs("").unwrap(), vec![]);
+        assert_eq!(
+            query_pairs(" a = 1 && b=2& ").unwrap(),
+            vec![("a", "1"), ("b", "2")]
+        );
+        // 值里的 `=` 保留
+        assert_eq!(query_pairs("a=b=c").unwrap(), vec![("a", "b=c")]);
+        assert_eq!(query_pairs("a=").unwrap(), vec![("a", "")]);
+    }
+
+    #[test]
+    fn query_pairs_requires_value() {
+        assert_eq!(
+            query_pairs("a=1&flag"),
+            Err(ParamError::MissingValue("flag".to_string()))
+        );
+    }
+
+    #[test]
+    fn pubkey_list_validates_each_address() {
+        assert_eq!(
+            pubkey_list(&format!(" {WSOL} ,,{USDC}")).unwrap(),
+            vec![WSOL.to_string(), USDC.to_string()]
+        );
+        assert_eq!(pubkey_list("").unwrap(), Vec::<String>::new());
+
+        // 长度不是 32 字节
+        assert!(matches!(
+            pubkey_list(&format!("{WSOL},abc")),
+            Err(ParamError::InvalidPubkey { value, source: CodecError::InvalidLength { .. } })
+                if value == "abc"
+        ));
+        // 非 Base58 字符
+        assert!(matches!(
+            pubkey_list("0OIl"),
+            Err(ParamError::InvalidPubkey {
+                source: CodecError::InvalidBase58(_),
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,21 @@
+/// 原始单位的 quote / base 比值换算为按精度的价格
+pub fn ui_price(raw_quote_per_base: f64, base_decimals: u32, quote_decimals: u32) -> f64 {
+    raw_quote_per_base * 10f64.powi(base_decimals as i32 - quote_decimals as i32)
+}
+
+/// 储备隐含价格 (quote / base，按精度换算)，任一侧储备为 0 时返回 0
+pub fn reserve_price(
+    base_reserve: u64,
+    quote_reserve: u64,
+    base_decimals: u32,
+    quote_decimals: u32,
+) -> f64 {
+    if base_reserve == 0 || quote_reserve == 0 {
+        return 0.0;
+    }
+    ui_price(
+        quote_reserve as f64 / base_reserve as f64,
+        base_decimals,
+        quote_decimals,
+    )
+}
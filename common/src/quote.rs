@@ -0,0 +1,14 @@
+use crate::constant::QUOTE_MINTS;
+
+/// 计价币优先级 (USDC > USDT > WSOL)，越小越优先；非计价币排在最后
+pub fn quote_rank(mint: &str) -> usize {
+    QUOTE_MINTS
+        .iter()
+        .position(|quote| *quote == mint)
+        .unwrap_or(QUOTE_MINTS.len())
+}
+
+/// 是否为计价币
+pub fn is_quote_mint(mint: &str) -> bool {
+    QUOTE_MINTS.contains(&mint)
+}
@@ -0,0 +1,100 @@
+use crate::account::AccountKeys;
+use crate::balance::{account_mint, pre_token_amount};
+use crate::instruction::FlattenedInstruction;
+use crate::quote::quote_rank;
+use crate::transfer::parse_token_transfer;
+use substreams_solana::pb::sf::solana::r#type::v1::TransactionStatusMeta;
+
+/// 由进出池子金库的转账得到的单跳成交
+/// base / quote 按计价币优先级确定: 付出的一方越接近计价币 (USDC > USDT > WSOL)，越视为买入
+pub struct VaultSwap {
+    pub base_mint: String,
+    pub quote_mint: String,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub base_decimals: u32,
+    pub quote_decimals: u32,
+    /// 用户付出 quote、收到 base
+    pub is_buy: bool,
+    /// 第一笔进出金库的转账的内层指令序号
+    pub first_transfer_index: u32,
+    /// 交易前的金库余额 (交易级记录，同一交易内多次经过同一池子时为交易开始前的储备)
+    pub base_reserve_before: u64,
+    pub quote_reserve_before: u64,
+}
+
+/// 根据 Swap 内部的 Token 转账提取金额
+/// 转入池子金库的为用户付出 (input)，从池子金库转出的为用户收到 (output)
+/// 任一方向找不到转账、金库没有余额记录、两侧 mint 相同或金额溢出时返回 None
+pub fn vault_swap(
+    meta: &TransactionStatusMeta,
+    account_keys: &AccountKeys,
+    children: &[FlattenedInstruction],
+    vaults: [u32; 2],
+) -> Option<VaultSwap> {
+    let mut input: Option<(u32, u64)> = None;
+    let mut output: Option<(u32, u64)> = None;
+    let mut first_transfer_index = None;
+
+    for child in children {
+        let transfer = match account_keys
+            .get(child.program_id_index as usize)
+            .and_then(|id| parse_token_transfer(id, child.accounts, child.data))
+        {
+            Some(t) => t,
+            None => continue,
+        };
+
+        let entry = if vaults.contains(&transfer.destination) {
+            input.get_or_insert((transfer.destination, 0))
+        } else if vaults.contains(&transfer.source) {
+            output.get_or_insert((transfer.source, 0))
+        } else {
+            continue;
+        };
+        entry.1 = entry.1.checked_add(transfer.amount)?;
+        first_transfer_index.get_or_insert(child.inner_instruction_index);
+    }
+
+    let (input_vault, input_amount) = input?;
+    let (output_vault, output_amount) = output?;
+    let (input_mint, input_decimals) = account_mint(meta, input_vault)?;
+    let (output_mint, output_decimals) = account_mint(meta, output_vault)?;
+    if input_mint == output_mint {
+        return None;
+    }
+
+    let input_reserve_before = pre_token_amount(meta, input_vault);
+    let output_reserve_before = pre_token_amount(meta, output_vault);
+
+    let is_buy = quote_rank(&output_mint) >= quote_rank(&input_mint);
+    let swap = if is_buy {
+        VaultSwap {
+            base_mint: output_mint,
+            quote_mint: input_mint,
+            base_amount: output_amount,
+            quote_amount: input_amount,
+            base_decimals: output_decimals,
+            quote_decimals: input_decimals,
+            is_buy,
+            first_transfer_index: first_transfer_index?,
+            base_reserve_before: output_reserve_before,
+            quote_reserve_before: input_reserve_before,
+        }
+    } else {
+        VaultSwap {
+            base_mint: input_mint,
+            quote_mint: output_mint,
+            base_amount: input_amount,
+            quote_amount: output_amount,
+            base_decimals: input_decimals,
+            quote_decimals: output_decimals,
+            is_buy,
+            first_transfer_index: first_transfer_index?,
+            base_reserve_before: input_reserve_before,
+            quote_reserve_before: output_reserve_before,
+        }
+    };
+
+    Some(swap)
+}
@@ -0,0 +1,44 @@
+/// System Program 指令: CreateAccount
+/// data: tag (u32) + lamports (u64) + space (u64) + owner (32); accounts: funder (0), new_account (1)
+const SYSTEM_CREATE_ACCOUNT: u32 = 0;
+
+/// System Program 指令: Transfer
+/// data: tag (u32) + lamports (u64); accounts: from (0), to (1)
+const SYSTEM_TRANSFER: u32 = 2;
+
+/// System Program 指令: CreateAccountWithSeed
+/// data: tag (u32) + base (32) + seed (u64 长度 + 字节) + lamports (u64) + space (u64) + owner (32)
+const SYSTEM_CREATE_ACCOUNT_WITH_SEED: u32 = 3;
+
+/// 解析 System Program 的注资指令，返回 (lamports, space)
+/// Transfer 不分配空间，space 为 None；接收方均为 accounts[1]
+pub fn parse_system_funding(data: &[u8]) -> Option<(u64, Option<u64>)> {
+    let read_u64 = |offset: usize| -> Option<u64> {
+        Some(u64::from_le_bytes(
+            data.get(offset..offset + 8)?.try_into().ok()?,
+        ))
+    };
+
+    match u32::from_le_bytes(data.get(0..4)?.try_into().ok()?) {
+        SYSTEM_CREATE_ACCOUNT => Some((read_u64(4)?, Some(read_u64(12)?))),
+        SYSTEM_TRANSFER => Some((read_u64(4)?, None)),
+        SYSTEM_CREATE_ACCOUNT_WITH_SEED => {
+            // 跳过 tag (4) + base (32)，读取 seed 长度后定位 lamports
+            let seed_len = usize::try_from(read_u64(36)?).ok()?;
+            let lamports_offset = 44usize.checked_add(seed_len)?;
+            Some((
+                read_u64(lamports_offset)?,
+                Some(read_u64(lamports_offset + 8)?),
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// 解析 System Program Transfer 指令，返回转账 lamports
+pub fn parse_system_transfer(data: &[u8]) -> Option<u64> {
+    match parse_system_funding(data)? {
+        (lamports, None) => Some(lamports),
+        _ => None,
+    }
+}
@@ -0,0 +1,62 @@
+use crate::constant::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID};
+
+/// SPL Token 指令: Transfer
+/// accounts: source (0), destination (1), authority (2)
+const TOKEN_TRANSFER: u8 = 3;
+
+/// SPL Token 指令: TransferChecked
+/// accounts: source (0), mint (1), destination (2), authority (3)
+const TOKEN_TRANSFER_CHECKED: u8 = 12;
+
+/// Token-2022 指令: TransferFeeExtension
+const TOKEN_TRANSFER_FEE_EXTENSION: u8 = 26;
+
+/// TransferFeeExtension 子指令: TransferCheckedWithFee
+/// data: 26 + 1 + amount (u64) + decimals (u8) + fee (u64)
+/// accounts: source (0), mint (1), destination (2), authority (3)
+const TRANSFER_CHECKED_WITH_FEE: u8 = 1;
+
+/// 一次 Token 转账 (账户为交易账户列表中的索引)
+pub struct TokenTransfer {
+    pub source: u32,
+    pub destination: u32,
+    /// 发送方转出的数量 (Token-2022 转账手续费从中扣除)
+    pub amount: u64,
+    /// TransferCheckedWithFee 显式声明的手续费
+    pub fee: Option<u64>,
+}
+
+/// 解析 SPL Token / Token-2022 的 Transfer、TransferChecked 与 TransferCheckedWithFee 指令
+pub fn parse_token_transfer(
+    program_id: &[u8],
+    accounts: &[u8],
+    data: &[u8],
+) -> Option<TokenTransfer> {
+    if program_id != TOKEN_PROGRAM_ID.0.as_slice()
+        && program_id != TOKEN_2022_PROGRAM_ID.0.as_slice()
+    {
+        return None;
+    }
+
+    let read_u64 = |offset: usize| -> Option<u64> {
+        Some(u64::from_le_bytes(
+            data.get(offset..offset + 8)?.try_into().ok()?,
+        ))
+    };
+
+    let (amount, fee, destination_position) = match *data.first()? {
+        TOKEN_TRANSFER => (read_u64(1)?, None, 1),
+        TOKEN_TRANSFER_CHECKED => (read_u64(1)?, None, 2),
+        TOKEN_TRANSFER_FEE_EXTENSION if *data.get(1)? == TRANSFER_CHECKED_WITH_FEE => {
+            (read_u64(2)?, Some(read_u64(11)?), 2)
+        }
+        _ => return None,
+    };
+
+    Some(TokenTransfer {
+        source: *accounts.first()? as u32,
+        destination: *accounts.get(destination_position)? as u32,
+        amount,
+        fee,
+    })
+}
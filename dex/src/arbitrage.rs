@@ -0,0 +1,123 @@
+use crate::constant::WSOL_MINT;
+use crate::pb::dex::{Arbitrage, Trade};
+use crate::route::{hop_flow, route_hop, HopFlow};
+use dex_common::account::AccountKeys;
+use dex_common::balance::owner_token_change;
+use dex_common::cost::{transaction_cost, TransactionCost};
+use dex_common::instruction::flatten_instructions;
+use substreams_solana::pb::sf::solana::r#type::v1::{
+    Block, ConfirmedTransaction, TransactionStatusMeta,
+};
+
+/// 检测单笔交易内的原子套利: 第一跳付出与最后一跳收到的 mint 相同，收到多于付出，
+/// 且签名者该 mint 的余额确实增加 (排除代他人执行的多跳路由)
+pub fn detect_arbitrage(group: &[Trade], block: &Block) -> Option<Arbitrage> {
+    let flows: Vec<(&Trade, HopFlow)> = group
+        .iter()
+        .filter_map(|trade| hop_flow(trade).map(|flow| (trade, flow)))
+        .collect();
+    if flows.len() < 2 {
+        return None;
+    }
+
+    let (first, first_flow) = flows.first()?;
+    let (_, last_flow) = flows.last()?;
+    let profit_mint = first_flow.input_mint;
+    if last_flow.output_mint != profit_mint {
+        return None;
+    }
+
+    // 毛利: 最后一跳收到 - 第一跳付出 (同一 mint 的原始精度)，不含手续费与小费
+    let profit_amount = last_flow
+        .output_amount
+        .checked_sub(first_flow.input_amount)
+        .filter(|&profit| profit > 0)?;
+
+    let tx = block.transactions.get(first.tx_index as usize)?;
+    let meta = tx.meta.as_ref()?;
+    let cost = transaction_cost_of(tx).unwrap_or_default();
+
+    let signer_change = signer_balance_change(meta, &first.user, profit_mint, &cost)?;
+    if signer_change <= 0 {
+        return None;
+    }
+
+    // WSOL 环路的净利润扣除手续费与 Jito 小费，其它 mint 无法与 lamports 相减
+    let net_profit = if profit_mint == WSOL_MINT {
+        let net = profit_amount as i128 - cost.fee as i128 - cost.jito_tip as i128;
+        net.to_string()
+    } else {
+        String::new()
+    };
+
+    let mut pools: Vec<String> = Vec::new();
+    let mut platforms: Vec<String> = Vec::new();
+    for (trade, _) in &flows {
+        if !pools.contains(&trade.pool) {
+            pools.push(trade.pool.clone());
+        }
+        if !platforms.contains(&trade.platform) {
+            platforms.push(trade.platform.clone());
+        }
+    }
+
+    Some(Arbitrage {
+        signature: first.signature.clone(),
+        user: first.user.clone(),
+        timestamp_ms: first.timestamp_ms,
+        slot: first.slot,
+        tx_index: first.tx_index,
+        profit_mint: profit_mint.to_string(),
+        profit_decimals: first_flow.input_decimals,
+        profit_amount,
+        signer_balance_change: signer_change.to_string(),
+        net_profit,
+        hops: flows
+            .iter()
+            .map(|(trade, flow)| route_hop(trade, flow))
+            .collect(),
+        pools,
+        platforms,
+        compute_units: cost.compute_units_consumed,
+        fee: cost.fee,
+        base_fee: cost.base_fee,
+        priority_fee: cost.priority_fee,
+        jito_tip: cost.jito_tip,
+        compute_unit_limit: cost.compute_unit_limit,
+        compute_unit_price: cost.compute_unit_price,
+    })
+}
+
+/// 签名者某个 mint 在交易前后的余额变化
+/// WSOL 额外计入签名者的原生 SOL 变化，并加回手续费与 Jito 小费 (二者单独报告)
+fn signer_balance_change(
+    meta: &TransactionStatusMeta,
+    user: &str,
+    mint: &str,
+    cost: &TransactionCost,
+) -> Option<i128> {
+    let token_change = owner_token_change(meta, user, mint);
+    if mint != WSOL_MINT {
+        return token_change;
+    }
+
+    let pre = *meta.pre_balances.first()? as i128;
+    let post = *meta.post_balances.first()? as i128;
+    Some(token_change.unwrap_or(0) + post - pre + cost.fee as i128 + cost.jito_tip as i128)
+}
+
+/// 交易的执行成本 (Compute Budget 设置、手续费构成与 Jito 小费)
+fn transaction_cost_of(tx: &ConfirmedTransaction) -> Option<TransactionCost> {
+    let transaction = tx.transaction.as_ref()?;
+    let message = transaction.message.as_ref()?;
+    let meta = tx.meta.as_ref()?;
+
+    let account_keys = AccountKeys::new(message, meta);
+    let instructions = flatten_instructions(message, meta);
+    Some(transaction_cost(
+        meta,
+        &account_keys,
+        &instructions,
+        transaction.signatures.len(),
+    ))
+}
@@ -0,0 +1,15 @@
+// ============================================================================
+// 滚动统计
+// ============================================================================
+
+/// 统计分桶粒度: UTC 整点小时
+pub const MS_PER_HOUR: u64 = 3_600_000;
+
+/// 按日统计的分桶粒度: UTC 自然日
+pub const MS_PER_DAY: u64 = 86_400_000;
+
+/// 滚动窗口包含的小时桶数量 (24 小时)
+pub const STATS_WINDOW_HOURS: u64 = 24;
+
+/// SOL/USD 定价只采用 quote (USDC) 不低于该值的 WSOL/USDC 成交，避免小额成交与浅池子的异常价格
+pub const MIN_SOL_PRICE_QUOTE_USD: f64 = 1_000.0;
@@ -0,0 +1,11 @@
+pub mod constant;
+
+pub use constant::{
+    // 滚动统计
+    MIN_SOL_PRICE_QUOTE_USD,
+    MS_PER_DAY,
+    MS_PER_HOUR,
+    STATS_WINDOW_HOURS,
+};
+
+pub use dex_common::constant::{USDC_MINT, USDT_MINT, WSOL_MINT};
@@ -0,0 +1,506 @@
+mod arbitrage;
+mod constant;
+mod normalize;
+mod params;
+#[allow(unused)]
+mod pb;
+mod pnl;
+mod route;
+mod sandwich;
+mod stats;
+mod token;
+mod watchlist;
+
+use arbitrage::detect_arbitrage;
+use dex_codec::{pubkey_to_string, signature_to_string};
+use normalize::normalize;
+use params::WatchlistParams;
+use pb::dex::{
+    Arbitrages, PoolStatsUpdates, Routes, Sandwiches, TokenTraderStatsUpdates, Trade, Trades,
+    WalletLot, WalletPnlUpdates, WatchlistTrades,
+};
+use pb::{meteora, orca, pumpfun, raydium};
+use pnl::{wallet_pnl, FifoStores};
+use route::{build_route, group_consecutive};
+use sandwich::detect_sandwiches;
+use stats::{is_priced, pool_stats, StatsStores};
+use std::collections::HashMap;
+use substreams::errors::Error;
+use substreams::store::{
+    StoreAddFloat64, StoreAddInt64, StoreGetFloat64, StoreGetInt64, StoreGetProto, StoreMaxFloat64,
+    StoreMinFloat64, StoreSetFloat64, StoreSetIfNotExistsFloat64, StoreSetIfNotExistsInt64,
+    StoreSetInt64, StoreSetProto,
+};
+use substreams_solana::pb::sf::solana::r#type::v1::Block;
+use token::{trader_stats, TokenStores, TraderStatsStores};
+use watchlist::watch_trade;
+
+// ============================================================================
+// 主 Map Handler: 合并各协议的 SwapEvent 为统一有序的成交流
+// base / quote 按池子归一化为规范方向 (见 normalize)
+// ============================================================================
+#[substreams::handlers::map]
+fn dex_trades(
+    meteora: meteora::SwapEvents,
+    raydium: raydium::SwapEvents,
+    orca: orca::SwapEvents,
+    pumpfun: pumpfun::SwapEvents,
+) -> Trades {
+    let mut trades: Vec<Trade> = Vec::new();
+    trades.extend(
+        meteora
+            .swaps
+            .into_iter()
+            .map(decode_raw_pubkeys)
+            .map(Trade::from)
+            .map(normalize),
+    );
+    trades.extend(raydium.swaps.into_iter().map(Trade::from).map(normalize));
+    trades.extend(orca.swaps.into_iter().map(Trade::from).map(normalize));
+    trades.extend(pumpfun.swaps.into_iter().map(Trade::from).map(normalize));
+
+    // 按 (tx_index, 指令路径) 排序，与链上执行顺序一致
+    // 稳定排序: 同一条指令产生的多跳成交保持各协议内部的输出顺序
+    trades.sort_by_key(|t| (t.tx_index, t.instruction_index, t.inner_instruction_index));
+
+    Trades { trades }
+}
+
+// ============================================================================
+// 多跳路由: 同一外层指令 (聚合器调用) 下的所有成交合并为一条 Route
+// 区块输入用于核对签名者的余额变化
+// ============================================================================
+#[substreams::handlers::map]
+fn dex_routes(trades: Trades, block: Block) -> Routes {
+    let routes = group_consecutive(&trades.trades, |t| (t.tx_index, t.instruction_index))
+        .into_iter()
+        .filter_map(|group| build_route(group, &block))
+        .collect();
+
+    Routes { routes }
+}
+
+// ============================================================================
+// 原子套利: 同一交易内第一跳付出与最后一跳收到的 mint 相同，且收到多于付出
+// ============================================================================
+#[substreams::handlers::map]
+fn dex_arbitrage(trades: Trades, block: Block) -> Arbitrages {
+    let arbitrages = group_consecutive(&trades.trades, |t| t.tx_index)
+        .into_iter()
+        .filter_map(|group| detect_arbitrage(group, &block))
+        .collect();
+
+    Arbitrages { arbitrages }
+}
+
+// ============================================================================
+// 三明治攻击: 同一池子上 前置 -> 受害 -> 后置 (按 tx_index 排序)
+// ============================================================================
+#[substreams::handlers::map]
+fn dex_sandwiches(trades: Trades) -> Sandwiches {
+    Sandwiches {
+        sandwiches: detect_sandwiches(&trades.trades),
+    }
+}
+
+// ============================================================================
+// 关注钱包: 只输出签名者或真实交易者在参数关注列表中的成交
+// ============================================================================
+#[substreams::handlers::map]
+fn dex_watchlist(params: String, trades: Trades) -> Result<WatchlistTrades, Error> {
+    let params = WatchlistParams::parse(&params)?;
+    if params.wallets.is_empty() {
+        return Ok(WatchlistTrades::default());
+    }
+
+    Ok(WatchlistTrades {
+        trades: trades
+            .trades
+            .iter()
+            .filter_map(|trade| watch_trade(trade, &params.wallets))
+            .collect(),
+    })
+}
+
+// ============================================================================
+// 池子滚动统计: 各 store 按 UTC 小时分桶累计，dex_pool_stats 汇总最近 24 个桶
+// ============================================================================
+
+/// SOL/USD 价格，用于把 WSOL 计价的成交折算为 USD
+#[substreams::handlers::store]
+fn store_dex_sol_price(trades: Trades, store: StoreSetFloat64) {
+    let mut vwap = stats::SolPriceVwap::default();
+    for trade in trades.trades.iter().filter(|t| is_priced(t)) {
+        vwap.store(trade, &store);
+    }
+}
+
+/// `{pool}` -> 池子最新成交时间，各小时桶 store 据此判断是否进入新的小时并删除过期的桶
+#[substreams::handlers::store]
+fn store_dex_pool_last_trade(trades: Trades, store: StoreSetInt64) {
+    for trade in trades.trades.iter().filter(|t| is_priced(t)) {
+        stats::store_pool_last_trade(trade, &store);
+    }
+}
+
+/// `{pool}:{period}:{trader}` -> 最新成交时间，用于窗口内交易者去重
+#[substreams::handlers::store]
+fn store_dex_pool_traders(trades: Trades, last_trades: StoreGetInt64, store: StoreSetInt64) {
+    for trade in trades.trades.iter().filter(|t| is_priced(t)) {
+        stats::store_trader(trade, &last_trades, &store);
+    }
+}
+
+/// 小时桶笔数: 成交 / 买入 / 卖出 / 不同交易者
+#[substreams::handlers::store]
+fn store_dex_pool_hourly_counts(
+    trades: Trades,
+    traders: StoreGetInt64,
+    last_trades: StoreGetInt64,
+    store: StoreAddInt64,
+) {
+    for trade in trades.trades.iter().filter(|t| is_priced(t)) {
+        stats::prune_hours(trade, &last_trades, &store);
+        stats::add_counts(trade, &traders, &store);
+    }
+}
+
+/// 小时桶成交量: base / quote / USD
+#[substreams::handlers::store]
+fn store_dex_pool_hourly_volumes(
+    trades: Trades,
+    sol_price: StoreGetFloat64,
+    last_trades: StoreGetInt64,
+    store: StoreAddFloat64,
+) {
+    for trade in trades.trades.iter().filter(|t| is_priced(t)) {
+        stats::prune_hours(trade, &last_trades, &store);
+        stats::add_volumes(trade, &sol_price, &store);
+    }
+}
+
+/// 小时桶开盘价 (桶内第一笔成交)
+#[substreams::handlers::store]
+fn store_dex_pool_hourly_open(
+    trades: Trades,
+    last_trades: StoreGetInt64,
+    store: StoreSetIfNotExistsFloat64,
+) {
+    for trade in trades.trades.iter().filter(|t| is_priced(t)) {
+        stats::prune_hours(trade, &last_trades, &store);
+        stats::store_open(trade, &store);
+    }
+}
+
+/// 小时桶最高价
+#[substreams::handlers::store]
+fn store_dex_pool_hourly_high(trades: Trades, last_trades: StoreGetInt64, store: StoreMaxFloat64) {
+    for trade in trades.trades.iter().filter(|t| is_priced(t)) {
+        stats::prune_hours(trade, &last_trades, &store);
+        stats::store_high(trade, &store);
+    }
+}
+
+/// 小时桶最低价
+#[substreams::handlers::store]
+fn store_dex_pool_hourly_low(trades: Trades, last_trades: StoreGetInt64, store: StoreMinFloat64) {
+    for trade in trades.trades.iter().filter(|t| is_priced(t)) {
+        stats::prune_hours(trade, &last_trades, &store);
+        stats::store_low(trade, &store);
+    }
+}
+
+/// 本区块有成交的池子各输出一条 PoolStats (按首次成交顺序)
+#[substreams::handlers::map]
+fn dex_pool_stats(
+    trades: Trades,
+    counts: StoreGetInt64,
+    volumes: StoreGetFloat64,
+    opens: StoreGetFloat64,
+    highs: StoreGetFloat64,
+    lows: StoreGetFloat64,
+    sol_price: StoreGetFloat64,
+) -> PoolStatsUpdates {
+    let stores = StatsStores {
+        counts,
+        volumes,
+        opens,
+        highs,
+        lows,
+        sol_price,
+    };
+
+    // 池子 -> 本区块最后一笔成交
+    let mut pools: Vec<&str> = Vec::new();
+    let mut last_trades: HashMap<&str, &Trade> = HashMap::new();
+    for trade in trades.trades.iter().filter(|t| is_priced(t)) {
+        if last_trades.insert(&trade.pool, trade).is_none() {
+            pools.push(&trade.pool);
+        }
+    }
+
+    PoolStatsUpdates {
+        pools: pools
+            .into_iter()
+            .map(|pool| pool_stats(last_trades[pool], &stores))
+            .collect(),
+    }
+}
+
+// ============================================================================
+// Token 交易者统计: 去重钱包、首次 / 最近成交时间、净头寸与按日买卖人数
+// ============================================================================
+
+/// `{mint}:{wallet}` -> 首次成交时间，同时用于钱包去重
+#[substreams::handlers::store]
+fn store_dex_token_traders(trades: Trades, store: StoreSetIfNotExistsInt64) {
+    for trade in trades.trades.iter().filter(|t| is_priced(t)) {
+        token::store_first_trade(trade, &store);
+    }
+}
+
+/// `{mint}:{wallet}` -> 最近成交时间
+#[substreams::handlers::store]
+fn store_dex_token_last_trade(trades: Trades, store: StoreSetInt64) {
+    for trade in trades.trades.iter().filter(|t| is_priced(t)) {
+        token::store_last_trade(trade, &store);
+    }
+}
+
+/// `{mint}:{wallet}` -> 累计买入 - 累计卖出 (原始精度)
+#[substreams::handlers::store]
+fn store_dex_token_positions(trades: Trades, store: StoreAddInt64) {
+    for trade in trades.trades.iter().filter(|t| is_priced(t)) {
+        token::add_position(trade, &store);
+    }
+}
+
+/// `day` -> 最新成交所在的 UTC 日，用于判断是否进入新的一天
+#[substreams::handlers::store]
+fn store_dex_token_last_day(trades: Trades, store: StoreSetInt64) {
+    for trade in trades.trades.iter().filter(|t| is_priced(t)) {
+        token::store_last_day(trade, &store);
+    }
+}
+
+/// `{day}:{mint}:{buy|sell}:{wallet}` -> 当日首次该方向成交的时间
+#[substreams::handlers::store]
+fn store_dex_token_daily_traders(
+    trades: Trades,
+    last_day: StoreGetInt64,
+    store: StoreSetIfNotExistsInt64,
+) {
+    for trade in trades.trades.iter().filter(|t| is_priced(t)) {
+        token::prune_days(trade, &last_day, &store);
+        token::store_daily_trader(trade, &store);
+    }
+}
+
+/// 按 mint 的去重钱包数、持有者数，以及按日的买卖人数与笔数
+#[substreams::handlers::store]
+fn store_dex_token_counts(
+    trades: Trades,
+    traders: StoreGetInt64,
+    positions: StoreGetInt64,
+    daily_traders: StoreGetInt64,
+    last_day: StoreGetInt64,
+    store: StoreAddInt64,
+) {
+    let stores = TokenStores {
+        traders,
+        positions,
+        daily_traders,
+    };
+    for trade in trades.trades.iter().filter(|t| is_priced(t)) {
+        token::prune_days(trade, &last_day, &store);
+        token::add_counts(trade, &stores, &store);
+    }
+}
+
+/// 本区块有成交的 mint 各输出一条 TokenTraderStats
+#[substreams::handlers::map]
+fn dex_token_trader_stats(
+    trades: Trades,
+    traders: StoreGetInt64,
+    last_trades: StoreGetInt64,
+    positions: StoreGetInt64,
+    counts: StoreGetInt64,
+) -> TokenTraderStatsUpdates {
+    let stores = TraderStatsStores {
+        traders,
+        last_trades,
+        positions,
+        counts,
+    };
+    let priced: Vec<Trade> = trades.trades.into_iter().filter(is_priced).collect();
+
+    TokenTraderStatsUpdates {
+        tokens: trader_stats(&priced, &stores),
+    }
+}
+
+// ============================================================================
+// 钱包盈亏: 按 (交易者, mint, quote) 与 (交易者, mint) 两个账本累计买卖，FIFO 计算已实现盈亏
+// ============================================================================
+
+/// `{position}:{field}` -> bought / sold / spent / received / unpriced
+#[substreams::handlers::store]
+fn store_dex_wallet_totals(trades: Trades, sol_price: StoreGetFloat64, store: StoreAddFloat64) {
+    for trade in trades.trades.iter().filter(|t| is_priced(t)) {
+        pnl::add_totals(trade, &sol_price, &store);
+    }
+}
+
+/// `{position}` -> 买入次数 (批次数量)
+#[substreams::handlers::store]
+fn store_dex_wallet_buy_counts(trades: Trades, store: StoreAddInt64) {
+    for trade in trades.trades.iter().filter(|t| is_priced(t)) {
+        pnl::add_buy_count(trade, &store);
+    }
+}
+
+/// `{position}:lot:{index}` -> 买入批次
+#[substreams::handlers::store]
+fn store_dex_wallet_lots(
+    trades: Trades,
+    totals: StoreGetFloat64,
+    buy_counts: StoreGetInt64,
+    sol_price: StoreGetFloat64,
+    store: StoreSetProto<WalletLot>,
+) {
+    for trade in trades.trades.iter().filter(|t| is_priced(t)) {
+        pnl::store_lot(trade, &totals, &buy_counts, &sol_price, &store);
+    }
+}
+
+/// `{position}` -> 历史最低净头寸
+#[substreams::handlers::store]
+fn store_dex_wallet_min_position(trades: Trades, totals: StoreGetFloat64, store: StoreMinFloat64) {
+    for trade in trades.trades.iter().filter(|t| is_priced(t)) {
+        pnl::store_min_position(trade, &totals, &store);
+    }
+}
+
+/// `{position}` -> 已实现盈亏 (quote 账本为 quote，USD 账本为 USD)
+#[substreams::handlers::store]
+fn store_dex_wallet_realized(
+    trades: Trades,
+    totals: StoreGetFloat64,
+    min_position: StoreGetFloat64,
+    buy_counts: StoreGetInt64,
+    lots: StoreGetProto<WalletLot>,
+    sol_price: StoreGetFloat64,
+    store: StoreAddFloat64,
+) {
+    let fifo = FifoStores {
+        totals,
+        min_position,
+        buy_counts,
+        lots,
+    };
+    for trade in trades.trades.iter().filter(|t| is_priced(t)) {
+        pnl::add_realized(trade, &fifo, &sol_price, &store);
+    }
+}
+
+/// 每笔成交输出一条 WalletPnl (成交之后的持仓与盈亏)
+#[substreams::handlers::map]
+fn dex_wallet_pnl(
+    trades: Trades,
+    totals: StoreGetFloat64,
+    min_position: StoreGetFloat64,
+    buy_counts: StoreGetInt64,
+    lots: StoreGetProto<WalletLot>,
+    realized: StoreGetFloat64,
+    sol_price: StoreGetFloat64,
+) -> WalletPnlUpdates {
+    let fifo = FifoStores {
+        totals,
+        min_position,
+        buy_counts,
+        lots,
+    };
+
+    WalletPnlUpdates {
+        updates: trades
+            .trades
+            .iter()
+            .filter(|t| is_priced(t))
+            .map(|trade| wallet_pnl(trade, &fifo, &realized, &sol_price))
+            .collect(),
+    }
+}
+
+// ============================================================================
+// 各协议 SwapEvent -> Trade
+// ============================================================================
+
+/// 各协议的 SwapEvent 字段与 SwapSide 取值一致，统一转换为 Trade
+/// trader 为空 (旧版本事件) 时取 user；没有成交前价格的协议 (Orca) 不传 price_before，取 0
+macro_rules! impl_trade_from_swap_event {
+    (@price_before $event:ident) => {
+        0.0
+    };
+    (@price_before $event:ident, $field:ident) => {
+        $event.$field
+    };
+    ($module:ident, $protocol:literal $(, $price_before:ident)?) => {
+        impl From<$module::SwapEvent> for Trade {
+            fn from(event: $module::SwapEvent) -> Self {
+                let trader = if event.trader.is_empty() {
+                    event.user.clone()
+                } else {
+                    event.trader.clone()
+                };
+                let price_before = impl_trade_from_swap_event!(@price_before event $(, $price_before)?);
+                Trade {
+                    protocol: $protocol.to_string(),
+                    platform: event.platform,
+                    pool: event.pool,
+                    signature: event.signature,
+                    user: event.user,
+                    trader,
+                    timestamp_ms: event.timestamp_ms,
+                    slot: event.slot,
+                    tx_index: event.tx_index,
+                    instruction_index: event.instruction_index,
+                    inner_instruction_index: event.inner_instruction_index,
+                    base_mint: event.base_mint,
+                    quote_mint: event.quote_mint,
+                    base_amount: event.base_amount,
+                    quote_amount: event.quote_amount,
+                    side: event.side,
+                    base_decimals: event.base_decimals,
+                    quote_decimals: event.quote_decimals,
+                    price_before,
+                }
+            }
+        }
+    };
+}
+
+/// Meteora 以 `pubkey_encoding=raw` 运行时 Base58 字段为空，改由 `*_raw` 字段转换
+/// 长度不符的原始字节 (不应出现) 保持为空
+fn decode_raw_pubkeys(mut event: meteora::SwapEvent) -> meteora::SwapEvent {
+    if event.signature.is_empty() {
+        event.signature = signature_to_string(&event.signature_raw).unwrap_or_default();
+    }
+    for (value, raw) in [
+        (&mut event.pool, &event.pool_raw),
+        (&mut event.user, &event.user_raw),
+        (&mut event.trader, &event.trader_raw),
+        (&mut event.base_mint, &event.base_mint_raw),
+        (&mut event.quote_mint, &event.quote_mint_raw),
+        (&mut event.outer_program_id, &event.outer_program_id_raw),
+    ] {
+        if value.is_empty() {
+            *value = pubkey_to_string(raw).unwrap_or_default();
+        }
+    }
+    event
+}
+
+impl_trade_from_swap_event!(meteora, "meteora", price_before);
+impl_trade_from_swap_event!(raydium, "raydium", price_before);
+impl_trade_from_swap_event!(orca, "orca");
+impl_trade_from_swap_event!(pumpfun, "pumpfun", price_before);
@@ -0,0 +1,43 @@
+use crate::pb::dex::{Trade, TradeSide};
+use crate::route::hop_flow;
+use dex_common::quote::quote_rank;
+
+/// 统一各协议的 base / quote 方向
+/// 各协议对 base 的约定不同 (如 Meteora 以用户收到的一方为 base，几乎所有成交都标记为买入)，
+/// 这里按池子的两个 mint 选出规范 quote: USDC > USDT > WSOL，都不是计价币时取字典序较小的 mint，
+/// 同一池子的买卖因此始终同向；用户付出 quote 为买入，收到 quote 为卖出
+/// 方向未知 (解析失败的占位成交) 原样返回
+pub fn normalize(mut trade: Trade) -> Trade {
+    let (input_mint, output_mint) = match hop_flow(&trade) {
+        Some(flow) => (flow.input_mint.to_string(), flow.output_mint.to_string()),
+        None => return trade,
+    };
+
+    let quote_is_input = match (quote_rank(&input_mint), quote_rank(&output_mint)) {
+        (input, output) if input != output => input < output,
+        _ => input_mint <= output_mint,
+    };
+    let side = if quote_is_input {
+        TradeSide::SideBuy
+    } else {
+        TradeSide::SideSell
+    };
+
+    // 规范 quote 与当前 quote 不一致时交换两腿
+    let canonical_quote = if quote_is_input {
+        &input_mint
+    } else {
+        &output_mint
+    };
+    if *canonical_quote != trade.quote_mint {
+        std::mem::swap(&mut trade.base_mint, &mut trade.quote_mint);
+        std::mem::swap(&mut trade.base_amount, &mut trade.quote_amount);
+        std::mem::swap(&mut trade.base_decimals, &mut trade.quote_decimals);
+        if trade.price_before > 0.0 {
+            trade.price_before = 1.0 / trade.price_before;
+        }
+    }
+    trade.side = side.into();
+
+    trade
+}
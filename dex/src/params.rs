@@ -0,0 +1,55 @@
+use dex_common::params::{pubkey_list, query_pairs};
+use std::collections::HashSet;
+use substreams::errors::Error;
+
+/// `dex_watchlist` 模块参数，格式与 URL query 相同: `key=value&key2=value2`
+/// 例: `wallets=<addr1>,<addr2>`，可重复出现，空列表时不输出任何成交
+#[derive(Default)]
+pub struct WatchlistParams {
+    pub wallets: HashSet<String>,
+}
+
+impl WatchlistParams {
+    /// 解析参数字符串，未知的键或非法地址直接报错
+    pub fn parse(params: &str) -> Result<Self, Error> {
+        let mut parsed = Self::default();
+
+        for (key, value) in query_pairs(params)? {
+            match key {
+                "wallets" => parsed.wallets.extend(pubkey_list(value)?),
+                other => return Err(Error::msg(format!("unknown param `{other}`"))),
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WSOL: &str = "So11111111111111111111111111111111111111112";
+    const USDC: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+    #[test]
+    fn empty_params_watch_nothing() {
+        assert!(WatchlistParams::parse("").unwrap().wallets.is_empty());
+    }
+
+    #[test]
+    fn wallets_can_repeat() {
+        let params =
+            WatchlistParams::parse(&format!("wallets={WSOL}&wallets={USDC},{WSOL}")).unwrap();
+        assert_eq!(params.wallets.len(), 2);
+        assert!(params.wallets.contains(WSOL));
+        assert!(params.wallets.contains(USDC));
+    }
+
+    #[test]
+    fn rejects_unknown_keys_and_invalid_wallets() {
+        assert!(WatchlistParams::parse(&format!("wallet={WSOL}")).is_err());
+        assert!(WatchlistParams::parse("wallets=not-a-pubkey").is_err());
+        assert!(WatchlistParams::parse("wallets").is_err());
+    }
+}
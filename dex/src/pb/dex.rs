@@ -0,0 +1,720 @@
+// @generated
+// This file is @generated by prost-build.
+/// ============================================================================
+/// 顶层输出：一个区块内所有 DEX 的成交 (按 tx_index + 指令路径排序)
+/// ============================================================================
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Trades {
+    #[prost(message, repeated, tag="1")]
+    pub trades: ::prost::alloc::vec::Vec<Trade>,
+}
+/// ============================================================================
+/// 标准化成交记录 - 合并 Meteora / Raydium / Orca / Pump.fun 的 SwapEvent
+/// ============================================================================
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Trade {
+    /// ========== 来源 ==========
+    ///
+    /// 协议: "meteora" / "raydium" / "orca" / "pumpfun"
+    #[prost(string, tag="1")]
+    pub protocol: ::prost::alloc::string::String,
+    /// 子平台: "meteora_dlmm" / "raydium_cpmm" / "orca_whirlpool" ...
+    #[prost(string, tag="2")]
+    pub platform: ::prost::alloc::string::String,
+    /// ========== 定位字段 ==========
+    ///
+    /// 流动性池地址 (Base58)
+    #[prost(string, tag="3")]
+    pub pool: ::prost::alloc::string::String,
+    /// 交易签名 (Base58)
+    #[prost(string, tag="4")]
+    pub signature: ::prost::alloc::string::String,
+    /// 交易发起者/Signer (Base58)
+    #[prost(string, tag="5")]
+    pub user: ::prost::alloc::string::String,
+    /// 真实交易者: Swap 指令中用户 Token 账户的 owner (Base58), 协议未解析时等于 user
+    #[prost(string, tag="18")]
+    pub trader: ::prost::alloc::string::String,
+    /// ========== 时间与排序 ==========
+    ///
+    /// 毫秒级时间戳
+    #[prost(uint64, tag="6")]
+    pub timestamp_ms: u64,
+    /// 区块高度
+    #[prost(uint64, tag="7")]
+    pub slot: u64,
+    /// 交易在区块中的序号
+    #[prost(uint32, tag="8")]
+    pub tx_index: u32,
+    /// 外层指令序号
+    #[prost(uint32, tag="9")]
+    pub instruction_index: u32,
+    /// 内层指令序号 (从 1 开始, 0 表示外层指令本身)
+    #[prost(uint32, tag="10")]
+    pub inner_instruction_index: u32,
+    /// ========== 交易对 ==========
+    /// 按池子归一化: quote 取 USDC > USDT > WSOL 中优先级最高者，都不是计价币时取字典序较小的 mint
+    ///
+    /// Base Token Mint
+    #[prost(string, tag="11")]
+    pub base_mint: ::prost::alloc::string::String,
+    /// Quote Token Mint
+    #[prost(string, tag="12")]
+    pub quote_mint: ::prost::alloc::string::String,
+    /// ========== 金额 (原始精度, 无小数) ==========
+    ///
+    /// Base Token 数量
+    #[prost(uint64, tag="13")]
+    pub base_amount: u64,
+    /// Quote Token 数量
+    #[prost(uint64, tag="14")]
+    pub quote_amount: u64,
+    /// ========== 方向 ==========
+    ///
+    /// buy = 用 quote 买 base, sell = 卖 base 换 quote
+    #[prost(enumeration="TradeSide", tag="15")]
+    pub side: i32,
+    /// ========== 精度 ==========
+    ///
+    /// Base Token 精度
+    #[prost(uint32, tag="16")]
+    pub base_decimals: u32,
+    /// Quote Token 精度
+    #[prost(uint32, tag="17")]
+    pub quote_decimals: u32,
+    /// ========== 成交前价格 ==========
+    ///
+    /// 成交前的池子价格 (quote / base, 按精度换算), 协议无法取得时为 0 (见各协议 SwapEvent.price_before)
+    #[prost(double, tag="19")]
+    pub price_before: f64,
+}
+/// ============================================================================
+/// 多跳路由：同一外层指令下的所有 Swap 跳合并为一条 Route
+/// ============================================================================
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Routes {
+    #[prost(message, repeated, tag="1")]
+    pub routes: ::prost::alloc::vec::Vec<Route>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Route {
+    /// ========== 定位字段 ==========
+    ///
+    /// <signature>:<instruction_index>
+    #[prost(string, tag="1")]
+    pub route_id: ::prost::alloc::string::String,
+    /// 交易签名 (Base58)
+    #[prost(string, tag="2")]
+    pub signature: ::prost::alloc::string::String,
+    /// 交易发起者/Signer (Base58)
+    #[prost(string, tag="3")]
+    pub user: ::prost::alloc::string::String,
+    /// 毫秒级时间戳
+    #[prost(uint64, tag="4")]
+    pub timestamp_ms: u64,
+    /// 区块高度
+    #[prost(uint64, tag="5")]
+    pub slot: u64,
+    /// 交易在区块中的序号
+    #[prost(uint32, tag="6")]
+    pub tx_index: u32,
+    /// 外层指令序号
+    #[prost(uint32, tag="7")]
+    pub instruction_index: u32,
+    /// ========== 净效果 (原始精度) ==========
+    ///
+    /// 第一跳付出的 Token
+    #[prost(string, tag="8")]
+    pub input_mint: ::prost::alloc::string::String,
+    /// 付出 input_mint 的各跳合计
+    #[prost(uint64, tag="9")]
+    pub input_amount: u64,
+    #[prost(uint32, tag="10")]
+    pub input_decimals: u32,
+    /// 最后一跳收到的 Token
+    #[prost(string, tag="11")]
+    pub output_mint: ::prost::alloc::string::String,
+    /// 收到 output_mint 的各跳合计
+    #[prost(uint64, tag="12")]
+    pub output_amount: u64,
+    #[prost(uint32, tag="13")]
+    pub output_decimals: u32,
+    /// 每单位 input 换得的 output (按精度换算后)
+    #[prost(double, tag="14")]
+    pub effective_price: f64,
+    /// ========== 路径 ==========
+    ///
+    /// 按执行顺序排列的各跳
+    #[prost(message, repeated, tag="15")]
+    pub hops: ::prost::alloc::vec::Vec<RouteHop>,
+    /// 经过的池子 (去重, 保持首次出现顺序)
+    #[prost(string, repeated, tag="16")]
+    pub pools: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// ========== 余额核对 ==========
+    ///
+    /// 签名者的 Token 余额变化与净效果一致
+    #[prost(bool, tag="17")]
+    pub balance_verified: bool,
+    /// 签名者 input_mint 余额变化 (十进制有符号整数, 无记录时为空)
+    #[prost(string, tag="18")]
+    pub signer_input_change: ::prost::alloc::string::String,
+    /// 签名者 output_mint 余额变化 (十进制有符号整数, 无记录时为空)
+    #[prost(string, tag="19")]
+    pub signer_output_change: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouteHop {
+    /// 协议: "meteora" / "raydium" / "orca" / "pumpfun"
+    #[prost(string, tag="1")]
+    pub protocol: ::prost::alloc::string::String,
+    /// 子平台
+    #[prost(string, tag="2")]
+    pub platform: ::prost::alloc::string::String,
+    /// 流动性池地址 (Base58)
+    #[prost(string, tag="3")]
+    pub pool: ::prost::alloc::string::String,
+    /// 本跳付出的 Token
+    #[prost(string, tag="4")]
+    pub input_mint: ::prost::alloc::string::String,
+    #[prost(uint64, tag="5")]
+    pub input_amount: u64,
+    /// 本跳收到的 Token
+    #[prost(string, tag="6")]
+    pub output_mint: ::prost::alloc::string::String,
+    #[prost(uint64, tag="7")]
+    pub output_amount: u64,
+    /// 内层指令序号
+    #[prost(uint32, tag="8")]
+    pub inner_instruction_index: u32,
+}
+/// ============================================================================
+/// 原子套利：同一交易内的 Swap 跳首尾 mint 相同，且签名者净余额为正
+/// ============================================================================
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Arbitrages {
+    #[prost(message, repeated, tag="1")]
+    pub arbitrages: ::prost::alloc::vec::Vec<Arbitrage>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Arbitrage {
+    /// ========== 定位字段 ==========
+    ///
+    /// 交易签名 (Base58)
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    /// 交易发起者/Signer (Base58)
+    #[prost(string, tag="2")]
+    pub user: ::prost::alloc::string::String,
+    /// 毫秒级时间戳
+    #[prost(uint64, tag="3")]
+    pub timestamp_ms: u64,
+    /// 区块高度
+    #[prost(uint64, tag="4")]
+    pub slot: u64,
+    /// 交易在区块中的序号
+    #[prost(uint32, tag="5")]
+    pub tx_index: u32,
+    /// ========== 利润 (原始精度) ==========
+    ///
+    /// 环路起止的 Token
+    #[prost(string, tag="6")]
+    pub profit_mint: ::prost::alloc::string::String,
+    #[prost(uint32, tag="7")]
+    pub profit_decimals: u32,
+    /// 毛利: 最后一跳收到 profit_mint - 第一跳付出 profit_mint (不含手续费与小费)
+    #[prost(uint64, tag="8")]
+    pub profit_amount: u64,
+    /// 签名者 profit_mint 的余额变化 (十进制有符号整数), WSOL 计入原生 SOL 并加回手续费与小费
+    #[prost(string, tag="9")]
+    pub signer_balance_change: ::prost::alloc::string::String,
+    /// profit_mint 为 WSOL 时: profit_amount - fee - jito_tip (十进制有符号整数), 其它 mint 为空
+    #[prost(string, tag="10")]
+    pub net_profit: ::prost::alloc::string::String,
+    /// ========== 路径 ==========
+    ///
+    /// 按执行顺序排列的各跳
+    #[prost(message, repeated, tag="11")]
+    pub hops: ::prost::alloc::vec::Vec<RouteHop>,
+    /// 经过的池子 (去重)
+    #[prost(string, repeated, tag="12")]
+    pub pools: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// 涉及的子平台 (去重)
+    #[prost(string, repeated, tag="13")]
+    pub platforms: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// ========== 成本 ==========
+    ///
+    /// 交易消耗的计算单元
+    #[prost(uint64, tag="14")]
+    pub compute_units: u64,
+    /// 交易总手续费 (lamports) = base_fee + priority_fee
+    #[prost(uint64, tag="15")]
+    pub fee: u64,
+    /// 签名费: 5000 lamports * 签名数
+    #[prost(uint64, tag="16")]
+    pub base_fee: u64,
+    /// 优先费 (lamports) = fee - base_fee
+    #[prost(uint64, tag="17")]
+    pub priority_fee: u64,
+    /// 转入 Jito tip 账户的 SOL 合计 (lamports)
+    #[prost(uint64, tag="18")]
+    pub jito_tip: u64,
+    /// SetComputeUnitLimit 请求的 CU 上限, 未设置时为 0
+    #[prost(uint32, tag="19")]
+    pub compute_unit_limit: u32,
+    /// SetComputeUnitPrice 设置的 CU 单价 (micro-lamports), 未设置时为 0
+    #[prost(uint64, tag="20")]
+    pub compute_unit_price: u64,
+}
+/// ============================================================================
+/// 三明治攻击：同一池子上 前置交易 -> 受害交易 -> 后置交易
+/// ============================================================================
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Sandwiches {
+    #[prost(message, repeated, tag="1")]
+    pub sandwiches: ::prost::alloc::vec::Vec<Sandwich>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Sandwich {
+    /// ========== 池子 ==========
+    ///
+    /// 流动性池地址 (Base58)
+    #[prost(string, tag="1")]
+    pub pool: ::prost::alloc::string::String,
+    /// 协议
+    #[prost(string, tag="2")]
+    pub protocol: ::prost::alloc::string::String,
+    /// 子平台
+    #[prost(string, tag="3")]
+    pub platform: ::prost::alloc::string::String,
+    /// 区块高度
+    #[prost(uint64, tag="4")]
+    pub slot: u64,
+    /// 毫秒级时间戳
+    #[prost(uint64, tag="5")]
+    pub timestamp_ms: u64,
+    /// ========== 三笔交易 ==========
+    ///
+    /// 攻击者 (前置与后置交易的签名者)
+    #[prost(string, tag="6")]
+    pub attacker: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub front_run_signature: ::prost::alloc::string::String,
+    #[prost(uint32, tag="8")]
+    pub front_run_tx_index: u32,
+    /// 受害者 (受害交易的签名者)
+    #[prost(string, tag="9")]
+    pub victim: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
+    pub victim_signature: ::prost::alloc::string::String,
+    #[prost(uint32, tag="11")]
+    pub victim_tx_index: u32,
+    #[prost(string, tag="12")]
+    pub back_run_signature: ::prost::alloc::string::String,
+    #[prost(uint32, tag="13")]
+    pub back_run_tx_index: u32,
+    /// 前置交易与受害交易的方向
+    #[prost(enumeration="TradeSide", tag="14")]
+    pub side: i32,
+    /// ========== 交易对 ==========
+    #[prost(string, tag="15")]
+    pub base_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="16")]
+    pub quote_mint: ::prost::alloc::string::String,
+    #[prost(uint32, tag="17")]
+    pub quote_decimals: u32,
+    /// ========== 损益估算 (quote 原始精度) ==========
+    ///
+    /// 受害者按前置交易之前的池子价格成交时可少付 / 多得的 quote (无成交前价格时按前置交易均价)
+    #[prost(uint64, tag="18")]
+    pub victim_loss_estimate: u64,
+    /// 攻击者后置与前置交易的 quote 差额 (十进制有符号整数, 未扣手续费)
+    #[prost(string, tag="19")]
+    pub attacker_profit: ::prost::alloc::string::String,
+}
+/// ============================================================================
+/// 池子滚动统计：最近 24 小时 (按 UTC 整点分桶) 的成交量、笔数与价格
+/// 每个区块只输出本区块有成交的池子
+/// ============================================================================
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolStatsUpdates {
+    #[prost(message, repeated, tag="1")]
+    pub pools: ::prost::alloc::vec::Vec<PoolStats>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolStats {
+    /// ========== 池子 ==========
+    ///
+    /// 流动性池地址 (Base58)
+    #[prost(string, tag="1")]
+    pub pool: ::prost::alloc::string::String,
+    /// 协议
+    #[prost(string, tag="2")]
+    pub protocol: ::prost::alloc::string::String,
+    /// 子平台
+    #[prost(string, tag="3")]
+    pub platform: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub base_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub quote_mint: ::prost::alloc::string::String,
+    #[prost(uint32, tag="6")]
+    pub base_decimals: u32,
+    #[prost(uint32, tag="7")]
+    pub quote_decimals: u32,
+    /// ========== 窗口 ==========
+    ///
+    /// 区块高度
+    #[prost(uint64, tag="8")]
+    pub slot: u64,
+    /// 区块时间 (毫秒)
+    #[prost(uint64, tag="9")]
+    pub timestamp_ms: u64,
+    /// 窗口起点 (第一个小时桶的起始时间, 毫秒)
+    #[prost(uint64, tag="10")]
+    pub window_start_ms: u64,
+    /// ========== 成交量 (按精度换算) ==========
+    #[prost(double, tag="11")]
+    pub volume_base: f64,
+    #[prost(double, tag="12")]
+    pub volume_quote: f64,
+    /// 仅计入 quote 可折算为 USD 的成交 (USDC / WSOL)
+    #[prost(double, tag="13")]
+    pub volume_usd: f64,
+    /// ========== 笔数 ==========
+    #[prost(uint64, tag="14")]
+    pub trades: u64,
+    #[prost(uint64, tag="15")]
+    pub buys: u64,
+    #[prost(uint64, tag="16")]
+    pub sells: u64,
+    /// 窗口内有成交的不同交易者 (Trade.trader)
+    #[prost(uint64, tag="17")]
+    pub unique_traders: u64,
+    /// ========== 价格 (每单位 base 的 quote, 按精度换算) ==========
+    ///
+    /// 最新成交价
+    #[prost(double, tag="18")]
+    pub price: f64,
+    /// 最新成交价折算 USD (无法折算时为 0)
+    #[prost(double, tag="19")]
+    pub price_usd: f64,
+    /// 窗口内第一笔成交价
+    #[prost(double, tag="20")]
+    pub open_price: f64,
+    #[prost(double, tag="21")]
+    pub high: f64,
+    #[prost(double, tag="22")]
+    pub low: f64,
+    /// (price - open_price) / open_price * 100
+    #[prost(double, tag="23")]
+    pub price_change_pct: f64,
+    /// 最新成交时间 (毫秒)
+    #[prost(uint64, tag="24")]
+    pub last_trade_ms: u64,
+}
+/// ============================================================================
+/// Token 交易者统计：去重钱包、持有者近似值与按 UTC 日的买卖人数
+/// 每笔成交中交易者 (Trade.trader) 收到的 mint 记为买入，付出的 mint 记为卖出; 计价币 (WSOL / USDC / USDT) 一侧不统计
+/// 每个区块只输出本区块有成交的 mint
+/// ============================================================================
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenTraderStatsUpdates {
+    #[prost(message, repeated, tag="1")]
+    pub tokens: ::prost::alloc::vec::Vec<TokenTraderStats>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenTraderStats {
+    /// ========== Token ==========
+    ///
+    /// Token Mint (Base58)
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    /// Token 精度
+    #[prost(uint32, tag="2")]
+    pub decimals: u32,
+    /// 区块高度
+    #[prost(uint64, tag="3")]
+    pub slot: u64,
+    /// 区块时间 (毫秒)
+    #[prost(uint64, tag="4")]
+    pub timestamp_ms: u64,
+    /// ========== 累计 ==========
+    ///
+    /// 交易过该 mint 的不同钱包 (按 Trade.trader)
+    #[prost(uint64, tag="5")]
+    pub unique_traders: u64,
+    /// 持有者近似值: 成交净头寸为正的钱包数 (不含转账与成交前已有的余额)
+    #[prost(uint64, tag="6")]
+    pub holders: u64,
+    /// ========== 当日 (UTC) ==========
+    ///
+    /// YYYY-MM-DD
+    #[prost(string, tag="7")]
+    pub day: ::prost::alloc::string::String,
+    /// 当日买入过的不同钱包
+    #[prost(uint64, tag="8")]
+    pub daily_buyers: u64,
+    /// 当日卖出过的不同钱包
+    #[prost(uint64, tag="9")]
+    pub daily_sellers: u64,
+    /// 当日涉及该 mint 的成交笔数
+    #[prost(uint64, tag="10")]
+    pub daily_trades: u64,
+    /// ========== 本区块成交的钱包 ==========
+    #[prost(message, repeated, tag="11")]
+    pub traders: ::prost::alloc::vec::Vec<TokenTrader>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenTrader {
+    /// 签名者 (Base58)
+    #[prost(string, tag="1")]
+    pub wallet: ::prost::alloc::string::String,
+    /// 首次成交时间 (毫秒)
+    #[prost(uint64, tag="2")]
+    pub first_trade_ms: u64,
+    /// 最近成交时间 (毫秒)
+    #[prost(uint64, tag="3")]
+    pub last_trade_ms: u64,
+    /// 累计买入 - 累计卖出 (原始精度)
+    #[prost(int64, tag="4")]
+    pub net_position: i64,
+}
+/// ============================================================================
+/// 钱包盈亏：按真实交易者 (Trade.trader) 与 mint 累计买卖并按 FIFO 计算已实现盈亏，同时维护两个账本
+/// quote 账本按 (钱包, mint, quote) 以 quote 计价; USD 账本按 (钱包, mint) 合并所有 quote 的成交并折算 USD
+/// 每笔成交输出一条更新 (按 dex_trades 顺序)
+/// ============================================================================
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WalletPnlUpdates {
+    #[prost(message, repeated, tag="1")]
+    pub updates: ::prost::alloc::vec::Vec<WalletPnl>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WalletPnl {
+    /// ========== 持仓 ==========
+    ///
+    /// 真实交易者 (Trade.trader, Base58)
+    #[prost(string, tag="1")]
+    pub wallet: ::prost::alloc::string::String,
+    /// Token Mint (成交的 base)
+    #[prost(string, tag="2")]
+    pub mint: ::prost::alloc::string::String,
+    /// 本笔成交的计价 Token Mint
+    #[prost(string, tag="3")]
+    pub quote_mint: ::prost::alloc::string::String,
+    #[prost(uint32, tag="4")]
+    pub decimals: u32,
+    #[prost(uint32, tag="5")]
+    pub quote_decimals: u32,
+    /// ========== 触发成交 ==========
+    ///
+    /// 交易签名 (Base58)
+    #[prost(string, tag="6")]
+    pub signature: ::prost::alloc::string::String,
+    /// 流动性池地址 (Base58)
+    #[prost(string, tag="7")]
+    pub pool: ::prost::alloc::string::String,
+    /// 子平台
+    #[prost(string, tag="8")]
+    pub platform: ::prost::alloc::string::String,
+    /// 区块高度
+    #[prost(uint64, tag="9")]
+    pub slot: u64,
+    /// 交易在区块中的序号
+    #[prost(uint32, tag="10")]
+    pub tx_index: u32,
+    /// 毫秒级时间戳
+    #[prost(uint64, tag="11")]
+    pub timestamp_ms: u64,
+    #[prost(enumeration="TradeSide", tag="12")]
+    pub side: i32,
+    /// 成交价 (每单位 base 的 quote)
+    #[prost(double, tag="13")]
+    pub price: f64,
+    /// 成交价折算 USD (每单位 base), quote 无法折算时为 0
+    #[prost(double, tag="14")]
+    pub price_usd: f64,
+    /// ========== quote 账本 (<wallet>:<mint>:<quote_mint>, 只含本笔 quote 的成交, 数量按精度换算) ==========
+    #[prost(double, tag="15")]
+    pub quote_bought: f64,
+    #[prost(double, tag="16")]
+    pub quote_sold: f64,
+    /// 买入累计花费 (quote)
+    #[prost(double, tag="17")]
+    pub quote_spent: f64,
+    /// 卖出累计所得 (quote)
+    #[prost(double, tag="18")]
+    pub quote_received: f64,
+    /// 未平仓数量: 尚未被卖出匹配的买入
+    #[prost(double, tag="19")]
+    pub quote_position: f64,
+    /// 未平仓部分的平均成本 (每单位 base 的 quote)
+    #[prost(double, tag="20")]
+    pub avg_entry_price: f64,
+    /// 卖出超过已记录买入的数量 (成本未知, 不计入已实现盈亏)
+    #[prost(double, tag="21")]
+    pub quote_unmatched_sold: f64,
+    /// 已实现盈亏 (quote)
+    #[prost(double, tag="22")]
+    pub realized_pnl: f64,
+    /// 未平仓部分按本笔成交价估值的浮动盈亏 (quote)
+    #[prost(double, tag="23")]
+    pub unrealized_pnl: f64,
+    /// ========== USD 账本 (<wallet>:<mint>, 合并所有 quote 的成交) ==========
+    #[prost(double, tag="24")]
+    pub total_bought: f64,
+    #[prost(double, tag="25")]
+    pub total_sold: f64,
+    /// 未平仓数量
+    #[prost(double, tag="26")]
+    pub position: f64,
+    /// 卖出超过已记录买入的数量
+    #[prost(double, tag="27")]
+    pub unmatched_sold: f64,
+    /// 未平仓中买入时 quote 无法折算 USD 的数量 (成本未知, 不计入均价与浮动盈亏)
+    #[prost(double, tag="28")]
+    pub unpriced_position: f64,
+    /// 可折算 USD 的买入累计花费 (按成交时的价格)
+    #[prost(double, tag="29")]
+    pub spent_usd: f64,
+    /// 可折算 USD 的卖出累计所得 (按成交时的价格)
+    #[prost(double, tag="30")]
+    pub received_usd: f64,
+    /// 未平仓中已定价部分的平均成本 (每单位 USD)
+    #[prost(double, tag="31")]
+    pub avg_entry_price_usd: f64,
+    /// 已实现盈亏 (USD), 只含可折算 USD 的卖出与已定价批次
+    #[prost(double, tag="32")]
+    pub realized_pnl_usd: f64,
+    /// 已定价未平仓部分按 price_usd 估值的浮动盈亏, 本笔无法折算时为 0
+    #[prost(double, tag="33")]
+    pub unrealized_pnl_usd: f64,
+}
+/// 一次买入形成的 FIFO 批次 (store_dex_wallet_lots 的值)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WalletLot {
+    /// 本批次之前的累计买入
+    #[prost(double, tag="1")]
+    pub bought_before: f64,
+    /// 本批次之前的累计花费 (所在账本的计价单位)
+    #[prost(double, tag="2")]
+    pub spent_before: f64,
+    /// 本批次买入数量
+    #[prost(double, tag="3")]
+    pub amount: f64,
+    /// 本批次花费 (所在账本的计价单位, 未定价时为 0)
+    #[prost(double, tag="4")]
+    pub cost: f64,
+    /// 本批次之前的累计未定价买入 (只出现在 USD 账本)
+    #[prost(double, tag="5")]
+    pub unpriced_before: f64,
+    /// 本批次可计价
+    #[prost(bool, tag="6")]
+    pub priced: bool,
+}
+/// ============================================================================
+/// 关注钱包成交流：签名者或真实交易者 (Trade.trader) 在关注列表中的成交
+/// 关注列表通过模块参数传入 (wallets=<addr>,<addr>)
+/// ============================================================================
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchlistTrades {
+    #[prost(message, repeated, tag="1")]
+    pub trades: ::prost::alloc::vec::Vec<WatchlistTrade>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchlistTrade {
+    /// 命中的成交 (slot / tx_index / timestamp_ms 即区块时间)
+    #[prost(message, optional, tag="1")]
+    pub trade: ::core::option::Option<Trade>,
+    /// 命中的关注钱包 (Base58)
+    #[prost(string, tag="2")]
+    pub wallet: ::prost::alloc::string::String,
+    /// 命中方式
+    #[prost(enumeration="WatchMatch", tag="3")]
+    pub matched_by: i32,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum WatchMatch {
+    MatchUnknown = 0,
+    /// 成交的签名者 (Trade.user)
+    MatchSigner = 1,
+    /// Swap 指令中用户 Token 账户的 owner (Trade.trader, 如经中继或代付成交)
+    MatchTokenOwner = 2,
+}
+impl WatchMatch {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            WatchMatch::MatchUnknown => "MATCH_UNKNOWN",
+            WatchMatch::MatchSigner => "MATCH_SIGNER",
+            WatchMatch::MatchTokenOwner => "MATCH_TOKEN_OWNER",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "MATCH_UNKNOWN" => Some(Self::MatchUnknown),
+            "MATCH_SIGNER" => Some(Self::MatchSigner),
+            "MATCH_TOKEN_OWNER" => Some(Self::MatchTokenOwner),
+            _ => None,
+        }
+    }
+}
+/// ============================================================================
+/// 交易方向 (取值与各协议的 SwapSide 保持一致)
+/// ============================================================================
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TradeSide {
+    SideUnknown = 0,
+    /// 买入 Base (用 Quote 换 Base)
+    SideBuy = 1,
+    /// 卖出 Base (用 Base 换 Quote)
+    SideSell = 2,
+}
+impl TradeSide {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TradeSide::SideUnknown => "SIDE_UNKNOWN",
+            TradeSide::SideBuy => "SIDE_BUY",
+            TradeSide::SideSell => "SIDE_SELL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SIDE_UNKNOWN" => Some(Self::SideUnknown),
+            "SIDE_BUY" => Some(Self::SideBuy),
+            "SIDE_SELL" => Some(Self::SideSell),
+            _ => None,
+        }
+    }
+}
+// @@protoc_insertion_point(module)
@@ -0,0 +1,49 @@
+// @generated
+// @@protoc_insertion_point(attribute:dex)
+pub mod dex {
+    include!("dex.rs");
+    // @@protoc_insertion_point(dex)
+}
+// @@protoc_insertion_point(attribute:meteora)
+pub mod meteora {
+    include!("meteora.rs");
+    // @@protoc_insertion_point(meteora)
+}
+// @@protoc_insertion_point(attribute:orca)
+pub mod orca {
+    include!("orca.rs");
+    // @@protoc_insertion_point(orca)
+}
+// @@protoc_insertion_point(attribute:pumpfun)
+pub mod pumpfun {
+    include!("pumpfun.rs");
+    // @@protoc_insertion_point(pumpfun)
+}
+// @@protoc_insertion_point(attribute:raydium)
+pub mod raydium {
+    include!("raydium.rs");
+    // @@protoc_insertion_point(raydium)
+}
+pub mod sf {
+    pub mod solana {
+        pub mod r#type {
+            // @@protoc_insertion_point(attribute:sf.solana.type.v1)
+            pub mod v1 {
+                include!("sf.solana.type.v1.rs");
+                // @@protoc_insertion_point(sf.solana.type.v1)
+            }
+        }
+    }
+    // @@protoc_insertion_point(attribute:sf.substreams)
+    pub mod substreams {
+        include!("sf.substreams.rs");
+        // @@protoc_insertion_point(sf.substreams)
+        pub mod solana {
+            // @@protoc_insertion_point(attribute:sf.substreams.solana.v1)
+            pub mod v1 {
+                include!("sf.substreams.solana.v1.rs");
+                // @@protoc_insertion_point(sf.substreams.solana.v1)
+            }
+        }
+    }
+}
@@ -2,8 +2,8 @@
 // This file is @generated by prost-build.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct MyData {
+pub struct Transactions {
     #[prost(message, repeated, tag="1")]
-    pub transactions: ::prost::alloc::vec::Vec<super::super::sf::solana::r#type::v1::ConfirmedTransaction>,
+    pub transactions: ::prost::alloc::vec::Vec<super::super::super::solana::r#type::v1::ConfirmedTransaction>,
 }
 // @@protoc_insertion_point(module)
@@ -0,0 +1,511 @@
+use crate::pb::dex::{Trade, TradeSide, WalletLot, WalletPnl};
+use crate::stats::{ordinal, quote_usd_price, trade_price, ui_amount, SOL_USD_KEY};
+use substreams::store::{
+    StoreAdd, StoreAddFloat64, StoreAddInt64, StoreGet, StoreGetFloat64, StoreGetInt64,
+    StoreGetProto, StoreMin, StoreMinFloat64, StoreSet, StoreSetProto,
+};
+
+// ============================================================================
+// Store key
+// ============================================================================
+
+// 累计字段: 数量按精度换算，花费 / 所得按所在账本的计价单位
+const FIELD_BOUGHT: &str = "bought";
+const FIELD_SOLD: &str = "sold";
+const FIELD_SPENT: &str = "spent";
+const FIELD_RECEIVED: &str = "received";
+/// 买入时无法计价的数量 (只出现在 USD 账本)
+const FIELD_UNPRICED: &str = "unpriced";
+
+/// quote 账本 `{wallet}:{mint}:{quote_mint}`: 只含该 quote 的成交，金额为 quote 数量
+/// dex_trades 已归一化，base 即交易的 Token；wallet 为真实交易者
+fn quote_position_key(trade: &Trade) -> String {
+    format!("{}:{}:{}", trade.trader, trade.base_mint, trade.quote_mint)
+}
+
+/// USD 账本 `{wallet}:{mint}`: 同一 Token 在所有 quote 上的成交合并计算，金额折算 USD
+fn usd_position_key(trade: &Trade) -> String {
+    format!("{}:{}", trade.trader, trade.base_mint)
+}
+
+fn field_key(position: &str, field: &str) -> String {
+    format!("{}:{}", position, field)
+}
+
+/// `{position}:lot:{index}` -> 第 index 次买入形成的批次 (从 0 开始)
+fn lot_key(position: &str, index: i64) -> String {
+    format!("{}:lot:{}", position, index)
+}
+
+fn is_buy(trade: &Trade) -> bool {
+    trade.side() == TradeSide::SideBuy
+}
+
+/// 本笔成交 quote 一侧的 USD 价值；quote 无法折算 USD 时为 None
+pub fn usd_value(trade: &Trade, sol_price: &StoreGetFloat64) -> Option<f64> {
+    let sol_usd = sol_price.get_at(ordinal(trade), SOL_USD_KEY);
+    quote_usd_price(&trade.quote_mint, sol_usd)
+        .map(|usd| ui_amount(trade.quote_amount, trade.quote_decimals) * usd)
+}
+
+/// 一笔成交在某个账本中的记录
+struct Entry {
+    position: String,
+    /// 本笔成交的金额 (账本计价单位)，无法计价时为 None
+    value: Option<f64>,
+}
+
+/// 每笔成交同时记入两个账本: [quote 账本, USD 账本]
+/// 持仓数量两个账本都按每笔成交累计；USD 账本中无法折算的买入记为未定价批次，无法折算的卖出不计入已实现盈亏
+fn entries(trade: &Trade, sol_price: &StoreGetFloat64) -> [Entry; 2] {
+    [
+        Entry {
+            position: quote_position_key(trade),
+            value: Some(ui_amount(trade.quote_amount, trade.quote_decimals)),
+        },
+        Entry {
+            position: usd_position_key(trade),
+            value: usd_value(trade, sol_price),
+        },
+    ]
+}
+
+// ============================================================================
+// FIFO
+// ============================================================================
+
+/// 按 FIFO 匹配所需的 store (两个账本共用，以 position key 区分)
+///
+/// 卖出依次消耗最早的买入批次；卖出超过已记录买入的部分 (转入或首次成交前已持有)
+/// 不消耗之后的买入。截至第 n 笔成交被匹配的累计买入 = 累计卖出 + min(0, 历史最低净头寸)，
+/// 因此只需累计值与最低净头寸，无需逐笔维护批次队列
+pub struct FifoStores {
+    pub totals: StoreGetFloat64,
+    pub min_position: StoreGetFloat64,
+    pub buy_counts: StoreGetInt64,
+    pub lots: StoreGetProto<WalletLot>,
+}
+
+/// 最早买入的若干单位中: 已定价部分的总成本与未定价的数量
+#[derive(Default)]
+struct FifoCost {
+    cost: f64,
+    unpriced: f64,
+}
+
+impl FifoStores {
+    fn total(&self, ordinal: u64, position: &str, field: &str) -> f64 {
+        self.totals
+            .get_at(ordinal, field_key(position, field))
+            .unwrap_or_default()
+    }
+
+    /// 截至 ordinal 已被卖出匹配的累计买入数量
+    fn matched(&self, ordinal: u64, position: &str) -> f64 {
+        let sold = self.total(ordinal, position, FIELD_SOLD);
+        let lowest = self
+            .min_position
+            .get_at(ordinal, position)
+            .unwrap_or_default();
+        matched_quantity(sold, lowest)
+    }
+
+    /// 最早买入的 quantity 个单位的成本 (按批次线性插值)
+    fn fifo_cost(&self, ordinal: u64, position: &str, quantity: f64) -> FifoCost {
+        let count = self
+            .buy_counts
+            .get_at(ordinal, position)
+            .unwrap_or_default();
+        fifo_cost(count, quantity, |index| {
+            self.lots
+                .get_at(ordinal, lot_key(position, index))
+                .unwrap_or_default()
+        })
+    }
+}
+
+/// 在 count 个按序号排列的批次中，计算最早买入的 quantity 个单位的成本
+fn fifo_cost(count: i64, quantity: f64, lot: impl Fn(i64) -> WalletLot) -> FifoCost {
+    if count <= 0 || quantity <= 0.0 {
+        return FifoCost::default();
+    }
+
+    // 二分查找最后一个 bought_before <= quantity 的批次
+    let (mut low, mut high) = (0, count - 1);
+    while low < high {
+        let mid = (low + high + 1) / 2;
+        if lot(mid).bought_before <= quantity {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+
+    lot_cost(&lot(low), quantity)
+}
+
+/// 被匹配的累计买入 = 累计卖出 + min(0, 历史最低净头寸)
+fn matched_quantity(sold: f64, lowest_position: f64) -> f64 {
+    (sold + lowest_position.min(0.0)).max(0.0)
+}
+
+/// 截至 lot 内第 quantity - bought_before 个单位的 FIFO 成本
+fn lot_cost(lot: &WalletLot, quantity: f64) -> FifoCost {
+    let mut cost = FifoCost {
+        cost: lot.spent_before,
+        unpriced: lot.unpriced_before,
+    };
+    if lot.amount <= 0.0 {
+        return cost;
+    }
+    let within = (quantity - lot.bought_before).clamp(0.0, lot.amount);
+    if lot.priced {
+        cost.cost += lot.cost * within / lot.amount;
+    } else {
+        cost.unpriced += within;
+    }
+    cost
+}
+
+// ============================================================================
+// Store 写入
+// ============================================================================
+
+/// 买入累计 bought / spent (无法计价时累计 unpriced)，卖出累计 sold / received
+pub fn add_totals(trade: &Trade, sol_price: &StoreGetFloat64, store: &StoreAddFloat64) {
+    let ordinal = ordinal(trade);
+    let base = ui_amount(trade.base_amount, trade.base_decimals);
+    let (amount_field, value_field) = if is_buy(trade) {
+        (FIELD_BOUGHT, FIELD_SPENT)
+    } else {
+        (FIELD_SOLD, FIELD_RECEIVED)
+    };
+
+    for entry in entries(trade, sol_price) {
+        store.add(ordinal, field_key(&entry.position, amount_field), base);
+        match entry.value {
+            Some(value) => store.add(ordinal, field_key(&entry.position, value_field), value),
+            None if is_buy(trade) => {
+                store.add(ordinal, field_key(&entry.position, FIELD_UNPRICED), base)
+            }
+            None => {}
+        }
+    }
+}
+
+pub fn add_buy_count(trade: &Trade, store: &StoreAddInt64) {
+    if is_buy(trade) {
+        store.add(ordinal(trade), quote_position_key(trade), 1);
+        store.add(ordinal(trade), usd_position_key(trade), 1);
+    }
+}
+
+/// 每次买入按序号写入一个批次，记录此前的累计买入、花费与未定价数量
+pub fn store_lot(
+    trade: &Trade,
+    totals: &StoreGetFloat64,
+    buy_counts: &StoreGetInt64,
+    sol_price: &StoreGetFloat64,
+    store: &StoreSetProto<WalletLot>,
+) {
+    if !is_buy(trade) {
+        return;
+    }
+    let ordinal = ordinal(trade);
+
+    for entry in entries(trade, sol_price) {
+        let before = |field: &str| {
+            totals
+                .get_at(ordinal - 1, field_key(&entry.position, field))
+                .unwrap_or_default()
+        };
+        let index = buy_counts
+            .get_at(ordinal - 1, &entry.position)
+            .unwrap_or_default();
+        let lot = WalletLot {
+            bought_before: before(FIELD_BOUGHT),
+            spent_before: before(FIELD_SPENT),
+            amount: ui_amount(trade.base_amount, trade.base_decimals),
+            cost: entry.value.unwrap_or_default(),
+            unpriced_before: before(FIELD_UNPRICED),
+            priced: entry.value.is_some(),
+        };
+        store.set(ordinal, lot_key(&entry.position, index), &lot);
+    }
+}
+
+/// 历史最低净头寸 (累计买入 - 累计卖出，不高于 0)
+pub fn store_min_position(trade: &Trade, totals: &StoreGetFloat64, store: &StoreMinFloat64) {
+    let ordinal = ordinal(trade);
+    for position in [quote_position_key(trade), usd_position_key(trade)] {
+        let total = |field: &str| {
+            totals
+                .get_at(ordinal, field_key(&position, field))
+                .unwrap_or_default()
+        };
+        let net = total(FIELD_BOUGHT) - total(FIELD_SOLD);
+        store.min(ordinal, position, net.min(0.0));
+    }
+}
+
+/// 卖出时按 FIFO 计算本笔已实现盈亏: 匹配部分中已定价批次的卖出所得 - 这些批次的成本
+/// 卖出本身无法计价时不计入 (该账本的已实现盈亏缺少这一笔)
+pub fn add_realized(
+    trade: &Trade,
+    fifo: &FifoStores,
+    sol_price: &StoreGetFloat64,
+    store: &StoreAddFloat64,
+) {
+    if is_buy(trade) {
+        return;
+    }
+    let ordinal = ordinal(trade);
+    let sold = ui_amount(trade.base_amount, trade.base_decimals);
+
+    for entry in entries(trade, sol_price) {
+        let proceeds = match entry.value {
+            Some(value) => value,
+            None => continue,
+        };
+        let position = entry.position;
+
+        let matched_before = fifo.matched(ordinal - 1, &position);
+        let matched_after = fifo.matched(ordinal, &position);
+        let before = fifo.fifo_cost(ordinal, &position, matched_before);
+        let after = fifo.fifo_cost(ordinal, &position, matched_after);
+        let matched = (matched_after - matched_before).max(0.0);
+        if let Some(pnl) = realized_pnl(proceeds, sold, matched, &before, &after) {
+            store.add(ordinal, position, pnl);
+        }
+    }
+}
+
+/// 本笔卖出的已实现盈亏: 匹配到已定价批次的部分按卖出均价计所得，减去这些批次的成本
+/// before / after 为卖出前后被匹配的累计买入对应的 FIFO 成本；没有匹配到已定价批次时返回 None
+fn realized_pnl(
+    proceeds: f64,
+    sold: f64,
+    matched: f64,
+    before: &FifoCost,
+    after: &FifoCost,
+) -> Option<f64> {
+    let priced = matched - (after.unpriced - before.unpriced);
+    if priced <= 0.0 || sold <= 0.0 {
+        return None;
+    }
+    Some(proceeds * (priced / sold).min(1.0) - (after.cost - before.cost))
+}
+
+// ============================================================================
+// 汇总
+// ============================================================================
+
+/// 一个账本在 ordinal 处的累计值与 FIFO 状态
+struct Ledger {
+    bought: f64,
+    sold: f64,
+    spent: f64,
+    received: f64,
+    /// 未平仓数量
+    open: f64,
+    /// 未平仓中买入时无法计价的数量
+    open_unpriced: f64,
+    /// 未平仓中已定价部分的成本
+    open_cost: f64,
+    unmatched_sold: f64,
+    realized: f64,
+}
+
+impl Ledger {
+    fn load(fifo: &FifoStores, realized: &StoreGetFloat64, ordinal: u64, position: &str) -> Self {
+        let total = |field: &str| fifo.total(ordinal, position, field);
+        let bought = total(FIELD_BOUGHT);
+        let sold = total(FIELD_SOLD);
+        let spent = total(FIELD_SPENT);
+        let matched = fifo.matched(ordinal, position);
+        let consumed = fifo.fifo_cost(ordinal, position, matched);
+
+        Ledger {
+            bought,
+            sold,
+            spent,
+            received: total(FIELD_RECEIVED),
+            open: (bought - matched).max(0.0),
+            open_unpriced: (total(FIELD_UNPRICED) - consumed.unpriced).max(0.0),
+            open_cost: (spent - consumed.cost).max(0.0),
+            unmatched_sold: (sold - matched).max(0.0),
+            realized: realized.get_at(ordinal, position).unwrap_or_default(),
+        }
+    }
+
+    /// 未平仓中已定价部分的平均成本
+    fn avg_entry_price(&self) -> f64 {
+        let priced = self.open - self.open_unpriced;
+        if priced > 0.0 {
+            self.open_cost / priced
+        } else {
+            0.0
+        }
+    }
+
+    /// 未平仓中已定价部分按 price 估值的浮动盈亏
+    fn unrealized(&self, price: f64) -> f64 {
+        (self.open - self.open_unpriced).max(0.0) * price - self.open_cost
+    }
+}
+
+/// 本笔成交之后 (ordinal 处) 两个账本的持仓与盈亏
+/// USD 字段在本笔成交无法折算 USD 时，price_usd 与 unrealized_pnl_usd 为 0
+pub fn wallet_pnl(
+    trade: &Trade,
+    fifo: &FifoStores,
+    realized: &StoreGetFloat64,
+    sol_price: &StoreGetFloat64,
+) -> WalletPnl {
+    let ordinal = ordinal(trade);
+    let price = trade_price(trade);
+    let price_usd = usd_value(trade, sol_price)
+        .map(|usd| usd / ui_amount(trade.base_amount, trade.base_decimals));
+
+    let quote = Ledger::load(fifo, realized, ordinal, &quote_position_key(trade));
+    let usd = Ledger::load(fifo, realized, ordinal, &usd_position_key(trade));
+
+    WalletPnl {
+        wallet: trade.trader.clone(),
+        mint: trade.base_mint.clone(),
+        quote_mint: trade.quote_mint.clone(),
+        decimals: trade.base_decimals,
+        quote_decimals: trade.quote_decimals,
+        signature: trade.signature.clone(),
+        pool: trade.pool.clone(),
+        platform: trade.platform.clone(),
+        slot: trade.slot,
+        tx_index: trade.tx_index,
+        timestamp_ms: trade.timestamp_ms,
+        side: trade.side,
+        price,
+        price_usd: price_usd.unwrap_or_default(),
+        quote_bought: quote.bought,
+        quote_sold: quote.sold,
+        quote_spent: quote.spent,
+        quote_received: quote.received,
+        quote_position: quote.open,
+        avg_entry_price: quote.avg_entry_price(),
+        quote_unmatched_sold: quote.unmatched_sold,
+        realized_pnl: quote.realized,
+        unrealized_pnl: quote.unrealized(price),
+        total_bought: usd.bought,
+        total_sold: usd.sold,
+        position: usd.open,
+        unmatched_sold: usd.unmatched_sold,
+        unpriced_position: usd.open_unpriced,
+        spent_usd: usd.spent,
+        received_usd: usd.received,
+        avg_entry_price_usd: usd.avg_entry_price(),
+        realized_pnl_usd: usd.realized,
+        unrealized_pnl_usd: price_usd.map_or(0.0, |price_usd| usd.unrealized(price_usd)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 依次买入: 10 个花费 100、10 个无法计价、10 个花费 300 (批次按 store_lot 的方式记录此前累计值)
+    fn lots() -> Vec<WalletLot> {
+        let buys = [(10.0, Some(100.0)), (10.0, None), (10.0, Some(300.0))];
+        let (mut bought, mut spent, mut unpriced) = (0.0, 0.0, 0.0);
+        buys.iter()
+            .map(|&(amount, cost): &(f64, Option<f64>)| {
+                let lot = WalletLot {
+                    bought_before: bought,
+                    spent_before: spent,
+                    amount,
+                    cost: cost.unwrap_or_default(),
+                    unpriced_before: unpriced,
+                    priced: cost.is_some(),
+                };
+                bought += amount;
+                match cost {
+                    Some(cost) => spent += cost,
+                    None => unpriced += amount,
+                }
+                lot
+            })
+            .collect()
+    }
+
+    fn cost_of(quantity: f64) -> (f64, f64) {
+        let cost = lot_cost_at(quantity);
+        (cost.cost, cost.unpriced)
+    }
+
+    #[test]
+    fn fifo_cost_interpolates_across_lots() {
+        assert_eq!(cost_of(0.0), (0.0, 0.0));
+        assert_eq!(cost_of(5.0), (50.0, 0.0));
+        assert_eq!(cost_of(10.0), (100.0, 0.0));
+        // 第二批无法计价: 只累计数量
+        assert_eq!(cost_of(15.0), (100.0, 5.0));
+        assert_eq!(cost_of(25.0), (250.0, 10.0));
+        assert_eq!(cost_of(30.0), (400.0, 10.0));
+        // 超出全部买入时停在最后一批
+        assert_eq!(cost_of(40.0), (400.0, 10.0));
+    }
+
+    #[test]
+    fn fifo_cost_without_lots_is_zero() {
+        let cost = fifo_cost(0, 10.0, |_| unreachable!());
+        assert_eq!((cost.cost, cost.unpriced), (0.0, 0.0));
+    }
+
+    #[test]
+    fn matched_excludes_sells_beyond_recorded_buys() {
+        assert_eq!(matched_quantity(0.0, 0.0), 0.0);
+        // 先卖出 5 个 (转入的持仓)，净头寸最低到 -5
+        assert_eq!(matched_quantity(5.0, -5.0), 0.0);
+        // 之后买入 20、再卖出 10: 累计卖出 15，只有 10 个匹配到买入
+        assert_eq!(matched_quantity(15.0, -5.0), 10.0);
+        assert_eq!(matched_quantity(15.0, 0.0), 15.0);
+    }
+
+    fn lot_cost_at(quantity: f64) -> FifoCost {
+        let lots = lots();
+        fifo_cost(lots.len() as i64, quantity, |index| {
+            lots[index as usize].clone()
+        })
+    }
+
+    #[test]
+    fn realized_counts_only_priced_lots() {
+        // 卖出 20 个共得 600: 匹配第一批 (成本 100) 与未定价的第二批，只有 10 个计入盈亏
+        let pnl = realized_pnl(600.0, 20.0, 20.0, &lot_cost_at(0.0), &lot_cost_at(20.0));
+        assert_eq!(pnl, Some(300.0 - 100.0));
+
+        // 再卖出 10 个得 200: 匹配第三批 (成本 300)
+        let pnl = realized_pnl(200.0, 10.0, 10.0, &lot_cost_at(20.0), &lot_cost_at(30.0));
+        assert_eq!(pnl, Some(-100.0));
+
+        // 只匹配到未定价批次
+        let pnl = realized_pnl(300.0, 5.0, 5.0, &lot_cost_at(10.0), &lot_cost_at(15.0));
+        assert_eq!(pnl, None);
+
+        // 卖出超过已记录买入: 只有匹配部分按比例计入所得
+        let pnl = realized_pnl(400.0, 20.0, 10.0, &lot_cost_at(0.0), &lot_cost_at(10.0));
+        assert_eq!(pnl, Some(200.0 - 100.0));
+    }
+
+    #[test]
+    fn position_keys() {
+        let trade = Trade {
+            user: "signer".to_string(),
+            trader: "trader".to_string(),
+            base_mint: "mint".to_string(),
+            quote_mint: "quote".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(quote_position_key(&trade), "trader:mint:quote");
+        assert_eq!(usd_position_key(&trade), "trader:mint");
+        assert_eq!(lot_key(&usd_position_key(&trade), 3), "trader:mint:lot:3");
+    }
+}
@@ -0,0 +1,182 @@
+use crate::pb::dex::{Route, RouteHop, Trade, TradeSide};
+use dex_common::balance::owner_token_change;
+use substreams_solana::pb::sf::solana::r#type::v1::Block;
+
+/// 单跳成交按方向换算后的付出 / 收到
+pub struct HopFlow<'a> {
+    pub input_mint: &'a str,
+    pub input_amount: u64,
+    pub input_decimals: u32,
+    pub output_mint: &'a str,
+    pub output_amount: u64,
+    pub output_decimals: u32,
+}
+
+/// buy: 付出 quote 收到 base；sell: 付出 base 收到 quote
+/// 方向未知 (解析失败的占位成交) 返回 None
+pub fn hop_flow(trade: &Trade) -> Option<HopFlow<'_>> {
+    match trade.side() {
+        TradeSide::SideBuy => Some(HopFlow {
+            input_mint: &trade.quote_mint,
+            input_amount: trade.quote_amount,
+            input_decimals: trade.quote_decimals,
+            output_mint: &trade.base_mint,
+            output_amount: trade.base_amount,
+            output_decimals: trade.base_decimals,
+        }),
+        TradeSide::SideSell => Some(HopFlow {
+            input_mint: &trade.base_mint,
+            input_amount: trade.base_amount,
+            input_decimals: trade.base_decimals,
+            output_mint: &trade.quote_mint,
+            output_amount: trade.quote_amount,
+            output_decimals: trade.quote_decimals,
+        }),
+        TradeSide::SideUnknown => None,
+    }
+}
+
+/// 将有序成交按 key 切分为连续分组 (如同一交易、同一外层指令)
+pub fn group_consecutive<K: PartialEq>(
+    trades: &[Trade],
+    key: impl Fn(&Trade) -> K,
+) -> Vec<&[Trade]> {
+    let mut groups = Vec::new();
+    let mut start = 0;
+
+    for end in 1..=trades.len() {
+        let boundary = end == trades.len() || key(&trades[end]) != key(&trades[start]);
+        if boundary {
+            groups.push(&trades[start..end]);
+            start = end;
+        }
+    }
+
+    groups
+}
+
+/// 将同一外层指令下的多跳成交合并为 Route，少于两跳的分组返回 None
+pub fn build_route(group: &[Trade], block: &Block) -> Option<Route> {
+    let flows: Vec<(&Trade, HopFlow)> = group
+        .iter()
+        .filter_map(|trade| hop_flow(trade).map(|flow| (trade, flow)))
+        .collect();
+    if flows.len() < 2 {
+        return None;
+    }
+
+    let (first, first_flow) = flows.first()?;
+    let (_, last_flow) = flows.last()?;
+
+    // 净效果: 第一跳付出的 Token 与最后一跳收到的 Token
+    // 拆单 (同一 Token 分多个池子成交) 时合计所有对应的跳
+    let input_mint = first_flow.input_mint;
+    let output_mint = last_flow.output_mint;
+    let input_amount = flows
+        .iter()
+        .filter(|(_, flow)| flow.input_mint == input_mint)
+        .fold(0u64, |total, (_, flow)| {
+            total.saturating_add(flow.input_amount)
+        });
+    let output_amount = flows
+        .iter()
+        .filter(|(_, flow)| flow.output_mint == output_mint)
+        .fold(0u64, |total, (_, flow)| {
+            total.saturating_add(flow.output_amount)
+        });
+
+    let mut pools: Vec<String> = Vec::new();
+    for (trade, _) in &flows {
+        if !pools.contains(&trade.pool) {
+            pools.push(trade.pool.clone());
+        }
+    }
+
+    let hops = flows
+        .iter()
+        .map(|(trade, flow)| route_hop(trade, flow))
+        .collect();
+
+    let mut route = Route {
+        route_id: format!("{}:{}", first.signature, first.instruction_index),
+        signature: first.signature.clone(),
+        user: first.user.clone(),
+        timestamp_ms: first.timestamp_ms,
+        slot: first.slot,
+        tx_index: first.tx_index,
+        instruction_index: first.instruction_index,
+        input_mint: input_mint.to_string(),
+        input_amount,
+        input_decimals: first_flow.input_decimals,
+        output_mint: output_mint.to_string(),
+        output_amount,
+        output_decimals: last_flow.output_decimals,
+        effective_price: effective_price(
+            input_amount,
+            first_flow.input_decimals,
+            output_amount,
+            last_flow.output_decimals,
+        ),
+        hops,
+        pools,
+        ..Default::default()
+    };
+
+    verify_balances(&mut route, block);
+    Some(route)
+}
+
+/// 单跳成交 -> RouteHop
+pub fn route_hop(trade: &Trade, flow: &HopFlow) -> RouteHop {
+    RouteHop {
+        protocol: trade.protocol.clone(),
+        platform: trade.platform.clone(),
+        pool: trade.pool.clone(),
+        input_mint: flow.input_mint.to_string(),
+        input_amount: flow.input_amount,
+        output_mint: flow.output_mint.to_string(),
+        output_amount: flow.output_amount,
+        inner_instruction_index: trade.inner_instruction_index,
+    }
+}
+
+/// 每单位 input 换得的 output (按精度换算)，input 为 0 时返回 0
+pub fn effective_price(
+    input_amount: u64,
+    input_decimals: u32,
+    output_amount: u64,
+    output_decimals: u32,
+) -> f64 {
+    if input_amount == 0 {
+        return 0.0;
+    }
+    let input = input_amount as f64 / 10f64.powi(input_decimals as i32);
+    let output = output_amount as f64 / 10f64.powi(output_decimals as i32);
+    output / input
+}
+
+/// 用签名者的 Token 余额变化核对路由净效果
+/// 原生 SOL (临时 WSOL 账户) 不在 token balances 中，此时无法核对
+fn verify_balances(route: &mut Route, block: &Block) {
+    let meta = match block
+        .transactions
+        .get(route.tx_index as usize)
+        .and_then(|tx| tx.meta.as_ref())
+    {
+        Some(meta) => meta,
+        None => return,
+    };
+
+    let input_change = owner_token_change(meta, &route.user, &route.input_mint);
+    let output_change = owner_token_change(meta, &route.user, &route.output_mint);
+
+    route.balance_verified = if route.input_mint == route.output_mint {
+        // 环形路由: 同一个 mint 的净变化 = 收到 - 付出
+        output_change == Some(route.output_amount as i128 - route.input_amount as i128)
+    } else {
+        input_change == Some(-(route.input_amount as i128))
+            && output_change == Some(route.output_amount as i128)
+    };
+    route.signer_input_change = input_change.map(|c| c.to_string()).unwrap_or_default();
+    route.signer_output_change = output_change.map(|c| c.to_string()).unwrap_or_default();
+}
@@ -0,0 +1,115 @@
+use crate::pb::dex::{Sandwich, Trade, TradeSide};
+use std::collections::BTreeMap;
+
+/// 在区块内按池子查找三明治攻击
+/// 前置: 攻击者与受害者同方向先成交；后置: 同一攻击者在受害者之后反向成交
+/// 方向取自 dex_trades 归一化后的 side；三笔成交的 base / quote 必须一致才比较金额
+pub fn detect_sandwiches(trades: &[Trade]) -> Vec<Sandwich> {
+    // BTreeMap 保证输出顺序确定；各池子内保持 dex_trades 的执行顺序
+    let mut by_pool: BTreeMap<&str, Vec<&Trade>> = BTreeMap::new();
+    for trade in trades {
+        if trade.side() != TradeSide::SideUnknown && !trade.pool.is_empty() {
+            by_pool.entry(&trade.pool).or_default().push(trade);
+        }
+    }
+
+    let mut sandwiches = Vec::new();
+    for pool_trades in by_pool.values() {
+        for (i, front) in pool_trades.iter().enumerate() {
+            let back_side = match front.side() {
+                TradeSide::SideBuy => TradeSide::SideSell,
+                _ => TradeSide::SideBuy,
+            };
+
+            // 同一攻击者在之后的交易中最近一次反向成交
+            let back = match pool_trades[i + 1..].iter().find(|t| {
+                t.user == front.user
+                    && t.tx_index > front.tx_index
+                    && t.side() == back_side
+                    && same_orientation(t, front)
+            }) {
+                Some(back) => back,
+                None => continue,
+            };
+
+            // 夹在两者之间、其他签名者的同方向成交均为受害交易
+            for victim in &pool_trades[i + 1..] {
+                if victim.tx_index <= front.tx_index || victim.tx_index >= back.tx_index {
+                    continue;
+                }
+                if victim.user == front.user
+                    || victim.side() != front.side()
+                    || !same_orientation(victim, front)
+                {
+                    continue;
+                }
+                sandwiches.push(build_sandwich(front, victim, back));
+            }
+        }
+    }
+
+    sandwiches.sort_by_key(|s| (s.front_run_tx_index, s.victim_tx_index));
+    sandwiches
+}
+
+/// 两笔成交的 base / quote 相同 (金额可直接比较)
+fn same_orientation(a: &Trade, b: &Trade) -> bool {
+    a.base_mint == b.base_mint && a.quote_mint == b.quote_mint
+}
+
+fn build_sandwich(front: &Trade, victim: &Trade, back: &Trade) -> Sandwich {
+    let is_buy = front.side() == TradeSide::SideBuy;
+
+    // 攻击者利润: 买入型为卖出所得 - 买入成本，卖出型为卖出所得 - 回购成本 (quote)
+    let attacker_profit = if is_buy {
+        back.quote_amount as i128 - front.quote_amount as i128
+    } else {
+        front.quote_amount as i128 - back.quote_amount as i128
+    };
+
+    Sandwich {
+        pool: front.pool.clone(),
+        protocol: front.protocol.clone(),
+        platform: front.platform.clone(),
+        slot: front.slot,
+        timestamp_ms: front.timestamp_ms,
+        attacker: front.user.clone(),
+        front_run_signature: front.signature.clone(),
+        front_run_tx_index: front.tx_index,
+        victim: victim.user.clone(),
+        victim_signature: victim.signature.clone(),
+        victim_tx_index: victim.tx_index,
+        back_run_signature: back.signature.clone(),
+        back_run_tx_index: back.tx_index,
+        side: front.side,
+        base_mint: front.base_mint.clone(),
+        quote_mint: front.quote_mint.clone(),
+        quote_decimals: front.quote_decimals,
+        victim_loss_estimate: victim_loss_estimate(front, victim, is_buy),
+        attacker_profit: attacker_profit.to_string(),
+    }
+}
+
+/// 以前置交易之前的池子价格 (front.price_before) 作为受害者的参考价，估算受害者多付 (买入) 或少得 (卖出) 的 quote
+/// 参考价为成交前的现价，估算值包含受害者自身的价格冲击；协议无法给出成交前价格时退回前置交易的成交均价
+fn victim_loss_estimate(front: &Trade, victim: &Trade, is_buy: bool) -> u64 {
+    // 受害者的 base 数量按参考价折算成 quote (原始精度)
+    let fair_quote = if front.price_before.is_finite() && front.price_before > 0.0 {
+        let raw_quote_per_base = front.price_before
+            * 10f64.powi(front.quote_decimals as i32 - front.base_decimals as i32);
+        // f64 转 u128 饱和截断，溢出或非有限值不会 panic
+        (victim.base_amount as f64 * raw_quote_per_base) as u128
+    } else if front.base_amount > 0 {
+        victim.base_amount as u128 * front.quote_amount as u128 / front.base_amount as u128
+    } else {
+        return 0;
+    };
+
+    let loss = if is_buy {
+        (victim.quote_amount as u128).saturating_sub(fair_quote)
+    } else {
+        fair_quote.saturating_sub(victim.quote_amount as u128)
+    };
+
+    u64::try_from(loss).unwrap_or(u64::MAX)
+}
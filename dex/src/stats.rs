@@ -0,0 +1,424 @@
+use crate::constant::{
+    MIN_SOL_PRICE_QUOTE_USD, MS_PER_DAY, MS_PER_HOUR, STATS_WINDOW_HOURS, USDC_MINT, USDT_MINT,
+    WSOL_MINT,
+};
+use crate::pb::dex::{PoolStats, Trade, TradeSide};
+use crate::route::effective_price;
+use substreams::store::{
+    StoreAdd, StoreAddFloat64, StoreAddInt64, StoreDelete, StoreGet, StoreGetFloat64,
+    StoreGetInt64, StoreMax, StoreMaxFloat64, StoreMin, StoreMinFloat64, StoreSet, StoreSetFloat64,
+    StoreSetIfNotExists, StoreSetIfNotExistsFloat64, StoreSetInt64,
+};
+
+// ============================================================================
+// Store key
+// ============================================================================
+
+/// SOL/USD 价格 (区块内达到最小金额的 WSOL/USDC 成交的成交量加权均价)
+pub const SOL_USD_KEY: &str = "sol_usd";
+
+/// 小时桶价格字段 (open / high / low 各为独立的 store)
+const FIELD_PRICE: &str = "price";
+
+// 小时桶计数字段
+const FIELD_TRADES: &str = "trades";
+const FIELD_BUYS: &str = "buys";
+const FIELD_SELLS: &str = "sells";
+const FIELD_TRADERS: &str = "traders";
+
+// 小时桶成交量字段 (按精度换算)
+const FIELD_BASE: &str = "base";
+const FIELD_QUOTE: &str = "quote";
+const FIELD_USD: &str = "usd";
+
+/// `{pool}:{period}:{trader}` -> 该交易者在池子上、该周期内的最新成交时间
+/// period = hour / 24，窗口内的最新成交只可能落在当前或上一个周期
+fn trader_key(pool: &str, period: u64, trader: &str) -> String {
+    format!("{}:{}:{}", pool, period, trader)
+}
+
+/// `{pool}:{hour}:{field}` -> 小时桶内的累计值或价格
+fn bucket_key(pool: &str, hour: u64, field: &str) -> String {
+    format!("{}:{}:{}", pool, hour, field)
+}
+
+/// `{pool}:{bucket}:`，用于删除整个过期的桶 (小时桶或交易者周期)
+fn bucket_prefix(pool: &str, bucket: u64) -> String {
+    format!("{}:{}:", pool, bucket)
+}
+
+// ============================================================================
+// 成交辅助
+// ============================================================================
+
+/// 成交在 store 中的 ordinal，与 dex_trades 的排序一致
+/// 从 1 开始，ordinal - 1 读取的是该成交写入之前的状态
+pub fn ordinal(trade: &Trade) -> u64 {
+    (((trade.tx_index as u64) << 32)
+        | ((trade.instruction_index as u64) << 16)
+        | trade.inner_instruction_index as u64)
+        + 1
+}
+
+/// 方向已知且两侧金额均非零的成交才计入统计
+pub fn is_priced(trade: &Trade) -> bool {
+    trade.side() != TradeSide::SideUnknown && trade.base_amount > 0 && trade.quote_amount > 0
+}
+
+/// 成交价: 每单位 base 的 quote (按精度换算)
+pub fn trade_price(trade: &Trade) -> f64 {
+    effective_price(
+        trade.base_amount,
+        trade.base_decimals,
+        trade.quote_amount,
+        trade.quote_decimals,
+    )
+}
+
+/// 按精度换算后的数量
+pub fn ui_amount(amount: u64, decimals: u32) -> f64 {
+    amount as f64 / 10f64.powi(decimals as i32)
+}
+
+/// 可用于 SOL/USD 定价的成交量 (SOL, USDC)，按精度换算
+/// 只取 WSOL/USDC 成交 (dex_trades 已归一化: base 为 WSOL，quote 为 USDC)，且 quote 不低于 MIN_SOL_PRICE_QUOTE_USD
+pub fn sol_usd_volume(trade: &Trade) -> Option<(f64, f64)> {
+    if (trade.base_mint.as_str(), trade.quote_mint.as_str()) != (WSOL_MINT, USDC_MINT) {
+        return None;
+    }
+    let quote = ui_amount(trade.quote_amount, trade.quote_decimals);
+    if quote < MIN_SOL_PRICE_QUOTE_USD {
+        return None;
+    }
+    Some((ui_amount(trade.base_amount, trade.base_decimals), quote))
+}
+
+/// 单位 quote 的 USD 价格: USDC / USDT 按 1 计，WSOL 按 SOL/USD，其余无法折算
+pub fn quote_usd_price(quote_mint: &str, sol_usd: Option<f64>) -> Option<f64> {
+    match quote_mint {
+        USDC_MINT | USDT_MINT => Some(1.0),
+        WSOL_MINT => sol_usd,
+        _ => None,
+    }
+}
+
+fn hour_of(timestamp_ms: u64) -> u64 {
+    timestamp_ms / MS_PER_HOUR
+}
+
+/// 交易者去重的周期: 每 24 个小时桶一个
+fn period_of(hour: u64) -> u64 {
+    hour / STATS_WINDOW_HOURS
+}
+
+/// 窗口的第一个小时桶
+fn window_start(hour: u64) -> u64 {
+    (hour + 1).saturating_sub(STATS_WINDOW_HOURS)
+}
+
+/// 毫秒时间戳对应的 UTC 日期 (YYYY-MM-DD)，按公历换算 (Howard Hinnant civil_from_days)
+pub fn utc_date(timestamp_ms: u64) -> String {
+    let days = (timestamp_ms / MS_PER_DAY) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+// ============================================================================
+// Store 写入
+// ============================================================================
+
+/// 区块内 SOL/USD 的成交量加权均价
+/// 单笔异常成交 (浅池子、被操纵的池子) 只按其成交量占比影响价格；区块内没有达标成交时沿用之前的价格
+#[derive(Default)]
+pub struct SolPriceVwap {
+    sol: f64,
+    usd: f64,
+}
+
+impl SolPriceVwap {
+    /// 计入一笔成交，并把截至该成交的加权均价写入 store
+    pub fn store(&mut self, trade: &Trade, store: &StoreSetFloat64) {
+        let (sol, usd) = match sol_usd_volume(trade) {
+            Some(volume) => volume,
+            None => return,
+        };
+        self.sol += sol;
+        self.usd += usd;
+        if self.sol > 0.0 {
+            store.set(ordinal(trade), SOL_USD_KEY, &(self.usd / self.sol));
+        }
+    }
+}
+
+pub fn store_pool_last_trade(trade: &Trade, store: &StoreSetInt64) {
+    store.set(ordinal(trade), &trade.pool, &(trade.timestamp_ms as i64));
+}
+
+pub fn store_trader(trade: &Trade, last_trades: &StoreGetInt64, store: &StoreSetInt64) {
+    let hour = hour_of(trade.timestamp_ms);
+    prune_buckets(trade, last_trades, store, period_of, 2);
+    store.set(
+        ordinal(trade),
+        trader_key(&trade.pool, period_of(hour), &trade.trader),
+        &(trade.timestamp_ms as i64),
+    );
+}
+
+/// 池子的本笔成交进入新的桶 (小时或交易者周期) 时，删除滑出窗口的桶
+/// 更早的桶已在之前的切换中删除，只需处理上一笔成交所在桶之前的 keep 个桶
+/// keep: 需要保留的桶数 (小时桶 24，交易者周期 2)
+fn prune_buckets(
+    trade: &Trade,
+    last_trades: &StoreGetInt64,
+    store: &impl StoreDelete,
+    bucket_of: fn(u64) -> u64,
+    keep: u64,
+) {
+    let ordinal = ordinal(trade);
+    let current = bucket_of(hour_of(trade.timestamp_ms));
+    let previous = match last_trades.get_at(ordinal - 1, &trade.pool) {
+        Some(timestamp_ms) => bucket_of(hour_of(timestamp_ms as u64)),
+        None => return,
+    };
+    if previous >= current {
+        return;
+    }
+
+    let first_kept = (current + 1).saturating_sub(keep);
+    for expired in (previous + 1).saturating_sub(keep)..first_kept.min(previous + 1) {
+        store.delete_prefix(ordinal as i64, &bucket_prefix(&trade.pool, expired));
+    }
+}
+
+/// 小时桶: 保留窗口内的 24 个
+pub fn prune_hours(trade: &Trade, last_trades: &StoreGetInt64, store: &impl StoreDelete) {
+    prune_buckets(trade, last_trades, store, |hour| hour, STATS_WINDOW_HOURS);
+}
+
+/// 笔数按小时累加；不同交易者 (trade.trader) 只计入其最新成交所在的小时桶
+/// (上一次成交所在的桶 -1，本次所在的桶 +1)，窗口内各桶求和即为去重人数
+pub fn add_counts(trade: &Trade, traders: &StoreGetInt64, store: &StoreAddInt64) {
+    let ordinal = ordinal(trade);
+    let hour = hour_of(trade.timestamp_ms);
+    let pool = trade.pool.as_str();
+
+    store.add(ordinal, bucket_key(pool, hour, FIELD_TRADES), 1);
+    let side_field = match trade.side() {
+        TradeSide::SideBuy => FIELD_BUYS,
+        _ => FIELD_SELLS,
+    };
+    store.add(ordinal, bucket_key(pool, hour, side_field), 1);
+
+    // 先查当前周期，再查上一个周期
+    let period = period_of(hour);
+    let previous_hour = (period.saturating_sub(1)..=period)
+        .rev()
+        .find_map(|period| traders.get_at(ordinal - 1, trader_key(pool, period, &trade.trader)))
+        .map(|timestamp_ms| hour_of(timestamp_ms as u64));
+    if previous_hour == Some(hour) {
+        return;
+    }
+    // 上一次成交已滑出窗口时，其所在的桶不再参与汇总 (且可能已被删除)
+    if let Some(previous_hour) = previous_hour.filter(|&h| h >= window_start(hour)) {
+        store.add(ordinal, bucket_key(pool, previous_hour, FIELD_TRADERS), -1);
+    }
+    store.add(ordinal, bucket_key(pool, hour, FIELD_TRADERS), 1);
+}
+
+/// 成交量按小时累加；USD 成交量使用成交时的 SOL/USD 价格折算
+pub fn add_volumes(trade: &Trade, sol_price: &StoreGetFloat64, store: &StoreAddFloat64) {
+    let ordinal = ordinal(trade);
+    let hour = hour_of(trade.timestamp_ms);
+    let pool = trade.pool.as_str();
+
+    let quote = ui_amount(trade.quote_amount, trade.quote_decimals);
+    store.add(
+        ordinal,
+        bucket_key(pool, hour, FIELD_BASE),
+        ui_amount(trade.base_amount, trade.base_decimals),
+    );
+    store.add(ordinal, bucket_key(pool, hour, FIELD_QUOTE), quote);
+
+    let sol_usd = sol_price.get_at(ordinal, SOL_USD_KEY);
+    if let Some(usd) = quote_usd_price(&trade.quote_mint, sol_usd) {
+        store.add(ordinal, bucket_key(pool, hour, FIELD_USD), quote * usd);
+    }
+}
+
+pub fn store_open(trade: &Trade, store: &StoreSetIfNotExistsFloat64) {
+    let key = bucket_key(&trade.pool, hour_of(trade.timestamp_ms), FIELD_PRICE);
+    store.set_if_not_exists(ordinal(trade), key, &trade_price(trade));
+}
+
+pub fn store_high(trade: &Trade, store: &StoreMaxFloat64) {
+    let key = bucket_key(&trade.pool, hour_of(trade.timestamp_ms), FIELD_PRICE);
+    store.max(ordinal(trade), key, trade_price(trade));
+}
+
+pub fn store_low(trade: &Trade, store: &StoreMinFloat64) {
+    let key = bucket_key(&trade.pool, hour_of(trade.timestamp_ms), FIELD_PRICE);
+    store.min(ordinal(trade), key, trade_price(trade));
+}
+
+// ============================================================================
+// 窗口汇总
+// ============================================================================
+
+/// dex_pool_stats 读取的各小时桶 store
+pub struct StatsStores {
+    pub counts: StoreGetInt64,
+    pub volumes: StoreGetFloat64,
+    pub opens: StoreGetFloat64,
+    pub highs: StoreGetFloat64,
+    pub lows: StoreGetFloat64,
+    pub sol_price: StoreGetFloat64,
+}
+
+/// 以池子在本区块的最后一笔成交为准，汇总截至该小时的 24 个小时桶
+pub fn pool_stats(last: &Trade, stores: &StatsStores) -> PoolStats {
+    let pool = last.pool.as_str();
+    let end_hour = hour_of(last.timestamp_ms);
+    let start_hour = window_start(end_hour);
+
+    let count = |hour: u64, field: &str| -> u64 {
+        let value = stores.counts.get_last(bucket_key(pool, hour, field));
+        value.unwrap_or_default().max(0) as u64
+    };
+    let volume = |hour: u64, field: &str| -> f64 {
+        stores
+            .volumes
+            .get_last(bucket_key(pool, hour, field))
+            .unwrap_or_default()
+    };
+
+    let mut stats = PoolStats {
+        pool: last.pool.clone(),
+        protocol: last.protocol.clone(),
+        platform: last.platform.clone(),
+        base_mint: last.base_mint.clone(),
+        quote_mint: last.quote_mint.clone(),
+        base_decimals: last.base_decimals,
+        quote_decimals: last.quote_decimals,
+        slot: last.slot,
+        timestamp_ms: last.timestamp_ms,
+        window_start_ms: start_hour * MS_PER_HOUR,
+        price: trade_price(last),
+        last_trade_ms: last.timestamp_ms,
+        ..Default::default()
+    };
+
+    let mut high: Option<f64> = None;
+    let mut low: Option<f64> = None;
+    for hour in start_hour..=end_hour {
+        stats.volume_base += volume(hour, FIELD_BASE);
+        stats.volume_quote += volume(hour, FIELD_QUOTE);
+        stats.volume_usd += volume(hour, FIELD_USD);
+        stats.trades += count(hour, FIELD_TRADES);
+        stats.buys += count(hour, FIELD_BUYS);
+        stats.sells += count(hour, FIELD_SELLS);
+        stats.unique_traders += count(hour, FIELD_TRADERS);
+
+        let key = bucket_key(pool, hour, FIELD_PRICE);
+        if stats.open_price == 0.0 {
+            stats.open_price = stores.opens.get_last(&key).unwrap_or_default();
+        }
+        if let Some(value) = stores.highs.get_last(&key) {
+            high = Some(high.map_or(value, |high| high.max(value)));
+        }
+        if let Some(value) = stores.lows.get_last(&key) {
+            low = Some(low.map_or(value, |low| low.min(value)));
+        }
+    }
+    stats.high = high.unwrap_or(stats.price);
+    stats.low = low.unwrap_or(stats.price);
+
+    if stats.open_price > 0.0 {
+        stats.price_change_pct = (stats.price - stats.open_price) / stats.open_price * 100.0;
+    }
+    let sol_usd = stores.sol_price.get_last(SOL_USD_KEY);
+    if let Some(usd) = quote_usd_price(&last.quote_mint, sol_usd) {
+        stats.price_usd = stats.price * usd;
+    }
+
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utc_date_converts_civil_days() {
+        assert_eq!(utc_date(0), "1970-01-01");
+        assert_eq!(utc_date(MS_PER_DAY - 1), "1970-01-01");
+        assert_eq!(utc_date(MS_PER_DAY), "1970-01-02");
+        // 闰日与世纪闰年
+        assert_eq!(utc_date(951_782_400_000), "2000-02-29");
+        assert_eq!(utc_date(1_709_164_800_000), "2024-02-29");
+        assert_eq!(utc_date(1_709_251_200_000), "2024-03-01");
+        assert_eq!(utc_date(1_735_689_599_999), "2024-12-31");
+        assert_eq!(utc_date(1_735_689_600_000), "2025-01-01");
+    }
+
+    fn sol_trade(sol: u64, usdc: u64) -> Trade {
+        Trade {
+            base_mint: WSOL_MINT.to_string(),
+            quote_mint: USDC_MINT.to_string(),
+            base_amount: sol,
+            quote_amount: usdc,
+            base_decimals: 9,
+            quote_decimals: 6,
+            side: TradeSide::SideBuy.into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn sol_usd_volume_requires_minimum_size() {
+        // 10 SOL / 1500 USDC
+        assert_eq!(
+            sol_usd_volume(&sol_trade(10_000_000_000, 1_500_000_000)),
+            Some((10.0, 1500.0))
+        );
+        // 低于 MIN_SOL_PRICE_QUOTE_USD 的成交不参与定价
+        assert_eq!(sol_usd_volume(&sol_trade(1_000_000_000, 150_000_000)), None);
+
+        let mut other = sol_trade(10_000_000_000, 1_500_000_000);
+        other.quote_mint = USDT_MINT.to_string();
+        assert_eq!(sol_usd_volume(&other), None);
+    }
+
+    #[test]
+    fn quote_usd_price_by_mint() {
+        assert_eq!(quote_usd_price(USDC_MINT, None), Some(1.0));
+        assert_eq!(quote_usd_price(USDT_MINT, Some(150.0)), Some(1.0));
+        assert_eq!(quote_usd_price(WSOL_MINT, Some(150.0)), Some(150.0));
+        assert_eq!(quote_usd_price(WSOL_MINT, None), None);
+        assert_eq!(quote_usd_price("other", Some(150.0)), None);
+    }
+
+    #[test]
+    fn ordinal_follows_instruction_path() {
+        let trade = Trade {
+            tx_index: 1,
+            instruction_index: 2,
+            inner_instruction_index: 3,
+            ..Default::default()
+        };
+        assert_eq!(ordinal(&trade), (1 << 32 | 2 << 16 | 3) + 1);
+        assert_eq!(ordinal(&Trade::default()), 1);
+    }
+}
@@ -0,0 +1,268 @@
+use crate::constant::MS_PER_DAY;
+use crate::pb::dex::{TokenTrader, TokenTraderStats, Trade};
+use crate::route::hop_flow;
+use crate::stats::{ordinal, utc_date};
+use dex_common::quote::is_quote_mint;
+use substreams::store::{
+    StoreAdd, StoreAddInt64, StoreDelete, StoreGet, StoreGetInt64, StoreSet, StoreSetIfNotExists,
+    StoreSetIfNotExistsInt64, StoreSetInt64,
+};
+
+// ============================================================================
+// Store key
+// ============================================================================
+
+/// 最新成交所在的 UTC 日 (`timestamp_ms / MS_PER_DAY`)
+const LAST_DAY_KEY: &str = "day";
+
+// 按 mint 累计的计数字段
+const FIELD_TRADERS: &str = "traders";
+const FIELD_HOLDERS: &str = "holders";
+
+// 按 UTC 日与 mint 累计的计数字段
+const FIELD_BUYERS: &str = "buyers";
+const FIELD_SELLERS: &str = "sellers";
+const FIELD_TRADES: &str = "trades";
+
+/// `{mint}:{wallet}` -> 首次 / 最近成交时间、净头寸
+fn wallet_key(mint: &str, wallet: &str) -> String {
+    format!("{}:{}", mint, wallet)
+}
+
+/// `{mint}:{field}` -> 按 mint 累计的计数
+fn token_key(mint: &str, field: &str) -> String {
+    format!("{}:{}", mint, field)
+}
+
+/// `{day}:{mint}:{field}` -> 按 UTC 日累计的计数
+fn daily_key(day: &str, mint: &str, field: &str) -> String {
+    format!("{}:{}:{}", day, mint, field)
+}
+
+/// `{day}:` -> 某一日的全部按日 key
+fn daily_prefix(day: &str) -> String {
+    format!("{}:", day)
+}
+
+/// `{day}:{mint}:{buy|sell}:{wallet}` -> 当日首次该方向成交的时间
+fn daily_wallet_key(day: &str, mint: &str, buy: bool, wallet: &str) -> String {
+    let side = if buy { "buy" } else { "sell" };
+    format!("{}:{}:{}:{}", day, mint, side, wallet)
+}
+
+// ============================================================================
+// 成交 -> Token 变化
+// ============================================================================
+
+/// 一笔成交对交易者 (trade.trader) 单个 mint 的影响
+pub struct TokenLeg<'a> {
+    pub mint: &'a str,
+    pub decimals: u32,
+    pub amount: u64,
+    pub buy: bool,
+}
+
+impl TokenLeg<'_> {
+    /// 买入为正、卖出为负的原始数量 (超出 i64 范围时截断)
+    fn signed_amount(&self) -> i64 {
+        let amount = i64::try_from(self.amount).unwrap_or(i64::MAX);
+        if self.buy {
+            amount
+        } else {
+            -amount
+        }
+    }
+}
+
+/// 收到的 mint 记为买入，付出的 mint 记为卖出；方向未知的成交返回空
+/// 计价币 (WSOL / USDC / USDT) 一侧不统计: 它们的持有者与买卖方由所有池子的计价腿累加，没有意义且 key 数量巨大
+pub fn token_legs(trade: &Trade) -> Vec<TokenLeg<'_>> {
+    let flow = match hop_flow(trade) {
+        Some(flow) => flow,
+        None => return Vec::new(),
+    };
+
+    vec![
+        TokenLeg {
+            mint: flow.output_mint,
+            decimals: flow.output_decimals,
+            amount: flow.output_amount,
+            buy: true,
+        },
+        TokenLeg {
+            mint: flow.input_mint,
+            decimals: flow.input_decimals,
+            amount: flow.input_amount,
+            buy: false,
+        },
+    ]
+    .into_iter()
+    .filter(|leg| !is_quote_mint(leg.mint))
+    .collect()
+}
+
+// ============================================================================
+// Store 写入
+// ============================================================================
+
+pub fn store_first_trade(trade: &Trade, store: &StoreSetIfNotExistsInt64) {
+    for leg in token_legs(trade) {
+        store.set_if_not_exists(
+            ordinal(trade),
+            wallet_key(leg.mint, &trade.trader),
+            &(trade.timestamp_ms as i64),
+        );
+    }
+}
+
+pub fn store_last_trade(trade: &Trade, store: &StoreSetInt64) {
+    for leg in token_legs(trade) {
+        store.set(
+            ordinal(trade),
+            wallet_key(leg.mint, &trade.trader),
+            &(trade.timestamp_ms as i64),
+        );
+    }
+}
+
+pub fn add_position(trade: &Trade, store: &StoreAddInt64) {
+    for leg in token_legs(trade) {
+        store.add(
+            ordinal(trade),
+            wallet_key(leg.mint, &trade.trader),
+            leg.signed_amount(),
+        );
+    }
+}
+
+pub fn store_last_day(trade: &Trade, store: &StoreSetInt64) {
+    let day = (trade.timestamp_ms / MS_PER_DAY) as i64;
+    store.set(ordinal(trade), LAST_DAY_KEY, &day);
+}
+
+/// 进入新的 UTC 日时删除上一笔成交所在日的按日 key
+/// 每个有 key 的日子之后的第一笔成交都会删除它，因此只需删除上一日
+pub fn prune_days(trade: &Trade, last_day: &StoreGetInt64, store: &impl StoreDelete) {
+    let ordinal = ordinal(trade);
+    let day = (trade.timestamp_ms / MS_PER_DAY) as i64;
+    if let Some(previous) = last_day.get_at(ordinal - 1, LAST_DAY_KEY) {
+        if previous < day {
+            let date = utc_date(previous as u64 * MS_PER_DAY);
+            store.delete_prefix(ordinal as i64, &daily_prefix(&date));
+        }
+    }
+}
+
+pub fn store_daily_trader(trade: &Trade, store: &StoreSetIfNotExistsInt64) {
+    let day = utc_date(trade.timestamp_ms);
+    for leg in token_legs(trade) {
+        store.set_if_not_exists(
+            ordinal(trade),
+            daily_wallet_key(&day, leg.mint, leg.buy, &trade.trader),
+            &(trade.timestamp_ms as i64),
+        );
+    }
+}
+
+/// 上游 store 读取: ordinal - 1 为本笔成交写入之前的状态
+pub struct TokenStores {
+    pub traders: StoreGetInt64,
+    pub positions: StoreGetInt64,
+    pub daily_traders: StoreGetInt64,
+}
+
+/// 新钱包、新的当日买卖方各计 1；净头寸由非正变为正时持有者 +1，反之 -1
+pub fn add_counts(trade: &Trade, stores: &TokenStores, store: &StoreAddInt64) {
+    let ordinal = ordinal(trade);
+    let day = utc_date(trade.timestamp_ms);
+    let wallet = trade.trader.as_str();
+
+    for leg in token_legs(trade) {
+        let key = wallet_key(leg.mint, wallet);
+
+        if stores.traders.get_at(ordinal - 1, &key).is_none() {
+            store.add(ordinal, token_key(leg.mint, FIELD_TRADERS), 1);
+        }
+
+        let before = stores.positions.get_at(ordinal - 1, &key).unwrap_or(0);
+        let after = stores.positions.get_at(ordinal, &key).unwrap_or(0);
+        if before <= 0 && after > 0 {
+            store.add(ordinal, token_key(leg.mint, FIELD_HOLDERS), 1);
+        } else if before > 0 && after <= 0 {
+            store.add(ordinal, token_key(leg.mint, FIELD_HOLDERS), -1);
+        }
+
+        store.add(ordinal, daily_key(&day, leg.mint, FIELD_TRADES), 1);
+        let daily_wallet = daily_wallet_key(&day, leg.mint, leg.buy, wallet);
+        if stores
+            .daily_traders
+            .get_at(ordinal - 1, daily_wallet)
+            .is_none()
+        {
+            let field = if leg.buy { FIELD_BUYERS } else { FIELD_SELLERS };
+            store.add(ordinal, daily_key(&day, leg.mint, field), 1);
+        }
+    }
+}
+
+// ============================================================================
+// 汇总
+// ============================================================================
+
+/// dex_token_trader_stats 读取的 store
+pub struct TraderStatsStores {
+    pub traders: StoreGetInt64,
+    pub last_trades: StoreGetInt64,
+    pub positions: StoreGetInt64,
+    pub counts: StoreGetInt64,
+}
+
+/// 按 mint 首次出现的顺序输出，钱包按首次成交的顺序去重
+pub fn trader_stats(trades: &[Trade], stores: &TraderStatsStores) -> Vec<TokenTraderStats> {
+    let mut tokens: Vec<TokenTraderStats> = Vec::new();
+
+    for trade in trades {
+        for leg in token_legs(trade) {
+            let position = match tokens.iter().position(|t| t.mint == leg.mint) {
+                Some(position) => position,
+                None => {
+                    tokens.push(new_stats(trade, &leg, stores));
+                    tokens.len() - 1
+                }
+            };
+            let stats = &mut tokens[position];
+            if stats.traders.iter().any(|t| t.wallet == trade.trader) {
+                continue;
+            }
+
+            let key = wallet_key(leg.mint, &trade.trader);
+            stats.traders.push(TokenTrader {
+                wallet: trade.trader.clone(),
+                first_trade_ms: stores.traders.get_last(&key).unwrap_or_default() as u64,
+                last_trade_ms: stores.last_trades.get_last(&key).unwrap_or_default() as u64,
+                net_position: stores.positions.get_last(&key).unwrap_or_default(),
+            });
+        }
+    }
+
+    tokens
+}
+
+fn new_stats(trade: &Trade, leg: &TokenLeg, stores: &TraderStatsStores) -> TokenTraderStats {
+    let day = utc_date(trade.timestamp_ms);
+    let count = |key: String| stores.counts.get_last(key).unwrap_or_default().max(0) as u64;
+
+    TokenTraderStats {
+        mint: leg.mint.to_string(),
+        decimals: leg.decimals,
+        slot: trade.slot,
+        timestamp_ms: trade.timestamp_ms,
+        unique_traders: count(token_key(leg.mint, FIELD_TRADERS)),
+        holders: count(token_key(leg.mint, FIELD_HOLDERS)),
+        daily_buyers: count(daily_key(&day, leg.mint, FIELD_BUYERS)),
+        daily_sellers: count(daily_key(&day, leg.mint, FIELD_SELLERS)),
+        daily_trades: count(daily_key(&day, leg.mint, FIELD_TRADES)),
+        day,
+        traders: Vec::new(),
+    }
+}
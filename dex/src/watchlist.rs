@@ -0,0 +1,20 @@
+use crate::pb::dex::{Trade, WatchMatch, WatchlistTrade};
+use std::collections::HashSet;
+
+/// 签名者在关注列表中直接命中；否则按真实交易者 (Swap 指令中用户 Token 账户的 owner) 命中
+/// (中继、代付或金库代为签名时签名者不是交易者)
+pub fn watch_trade(trade: &Trade, wallets: &HashSet<String>) -> Option<WatchlistTrade> {
+    let (wallet, matched_by) = if wallets.contains(&trade.user) {
+        (&trade.user, WatchMatch::MatchSigner)
+    } else if wallets.contains(&trade.trader) {
+        (&trade.trader, WatchMatch::MatchTokenOwner)
+    } else {
+        return None;
+    };
+
+    Some(WatchlistTrade {
+        trade: Some(trade.clone()),
+        wallet: wallet.clone(),
+        matched_by: matched_by as i32,
+    })
+}
@@ -0,0 +1,439 @@
+// ============================================================================
+// 根据 idls/*.json (Anchor IDL) 生成每个程序的指令 / 事件 discriminator 表，
+// 以及每个程序的指令参数类型与解码器 (mod <程序名> { Instruction, <指令>Args, 参数引用的自定义类型 })
+// 输出 $OUT_DIR/idl.rs，由 src/idl.rs include
+// ============================================================================
+
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+const IDL_DIR: &str = "idls";
+
+fn main() {
+    let idl_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join(IDL_DIR);
+    println!("cargo:rerun-if-changed={}", idl_dir.display());
+
+    // 按文件名排序，保证生成结果确定
+    let mut paths: Vec<PathBuf> = fs::read_dir(&idl_dir)
+        .unwrap_or_else(|e| panic!("读取 {} 失败: {}", idl_dir.display(), e))
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+
+    let mut out = String::new();
+    let mut programs: Vec<(String, String, String)> = Vec::new();
+
+    for path in paths {
+        println!("cargo:rerun-if-changed={}", path.display());
+        let idl: Value = fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|json| serde_json::from_str(&json).map_err(|e| e.to_string()))
+            .unwrap_or_else(|e| panic!("解析 {} 失败: {}", path.display(), e));
+
+        // Anchor >= 0.30: address / metadata.name；旧格式: metadata.address / name
+        let address = idl["address"]
+            .as_str()
+            .or_else(|| idl["metadata"]["address"].as_str())
+            .unwrap_or_else(|| panic!("{} 缺少程序地址", path.display()));
+        let name = idl["metadata"]["name"]
+            .as_str()
+            .or_else(|| idl["name"].as_str())
+            .unwrap_or_else(|| panic!("{} 缺少程序名", path.display()));
+        let const_name = format!("{}_INSTRUCTIONS", to_snake_case(name).to_uppercase());
+
+        writeln!(out, "/// {} ({})", name, address).unwrap();
+        writeln!(out, "pub const {}: &[IdlInstruction] = &[", const_name).unwrap();
+        for instruction in idl["instructions"].as_array().into_iter().flatten() {
+            let instruction_name = to_snake_case(
+                instruction["name"]
+                    .as_str()
+                    .unwrap_or_else(|| panic!("{} 中存在没有 name 的指令", path.display())),
+            );
+            let discriminator = discriminator(instruction, "global", &instruction_name);
+            writeln!(
+                out,
+                "    IdlInstruction {{ name: {:?}, discriminator: {:?} }},",
+                instruction_name, discriminator
+            )
+            .unwrap();
+        }
+        writeln!(out, "];\n").unwrap();
+
+        // 事件 (Anchor emit_cpi!): discriminator = sha256("event:<Name>") 前 8 字节
+        let events_name = format!("{}_EVENTS", to_snake_case(name).to_uppercase());
+        writeln!(out, "/// {} 事件", name).unwrap();
+        writeln!(out, "pub const {}: &[IdlEvent] = &[", events_name).unwrap();
+        for event in idl["events"].as_array().into_iter().flatten() {
+            let event_name = event["name"]
+                .as_str()
+                .unwrap_or_else(|| panic!("{} 中存在没有 name 的事件", path.display()));
+            let discriminator = discriminator(event, "event", event_name);
+            writeln!(
+                out,
+                "    IdlEvent {{ name: {:?}, discriminator: {:?} }},",
+                event_name, discriminator
+            )
+            .unwrap();
+        }
+        writeln!(out, "];\n").unwrap();
+
+        programs.push((address.to_string(), const_name, events_name));
+
+        write_program_module(&mut out, &idl, &to_snake_case(name), address, &path);
+    }
+
+    writeln!(out, "/// 所有 IDL 程序: 程序 ID -> 指令表").unwrap();
+    writeln!(out, "pub const IDL_PROGRAMS: &[IdlProgram] = &[").unwrap();
+    for (address, const_name, events_name) in &programs {
+        writeln!(
+            out,
+            "    IdlProgram {{ program_id: Pubkey(b58!({:?})), instructions: {}, events: {} }},",
+            address, const_name, events_name
+        )
+        .unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("idl.rs");
+    fs::write(&out_path, out).unwrap_or_else(|e| panic!("写入 {} 失败: {}", out_path.display(), e));
+}
+
+/// discriminator: IDL 自带 (Anchor >= 0.30) 或按 sha256("<namespace>:<name>") 前 8 字节计算
+/// 指令的 namespace 为 "global"，事件为 "event"
+fn discriminator(item: &Value, namespace: &str, name: &str) -> [u8; 8] {
+    if let Some(bytes) = item["discriminator"].as_array() {
+        let bytes: Vec<u8> = bytes
+            .iter()
+            .map(|b| {
+                b.as_u64()
+                    .and_then(|b| u8::try_from(b).ok())
+                    .expect("discriminator 不是字节数组")
+            })
+            .collect();
+        return bytes.try_into().expect("discriminator 长度不是 8");
+    }
+
+    let hash = Sha256::digest(format!("{}:{}", namespace, name).as_bytes());
+    hash[..8].try_into().unwrap()
+}
+
+// ============================================================================
+// 指令参数类型与解码器
+// ============================================================================
+
+/// 生成一个程序的模块: PROGRAM_ID、参数引用的自定义类型、每条指令的 `<指令>Args` 与 `Instruction` 枚举
+fn write_program_module(out: &mut String, idl: &Value, module: &str, address: &str, path: &Path) {
+    let types: BTreeMap<&str, &Value> = idl["types"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|ty| Some((ty["name"].as_str()?, ty)))
+        .collect();
+    let instructions: Vec<&Value> = idl["instructions"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .collect();
+
+    // 只生成指令参数直接或间接引用的类型 (账户 / 事件类型可能是 zero-copy 布局，不按 Borsh 解码)
+    let mut used = BTreeSet::new();
+    for instruction in &instructions {
+        for arg in instruction["args"].as_array().into_iter().flatten() {
+            collect_defined(&arg["type"], &types, &mut used, path);
+        }
+    }
+
+    writeln!(
+        out,
+        "#[allow(dead_code, clippy::large_enum_variant, clippy::enum_variant_names)]"
+    )
+    .unwrap();
+    writeln!(out, "pub mod {} {{", module).unwrap();
+    writeln!(out, "    use super::*;\n").unwrap();
+    writeln!(
+        out,
+        "    pub const PROGRAM_ID: Pubkey = Pubkey(b58!({:?}));\n",
+        address
+    )
+    .unwrap();
+
+    for name in &used {
+        let ty = &types[name.as_str()]["type"];
+        match ty["kind"].as_str() {
+            Some("struct") => write_struct(out, name, &ty["fields"], path),
+            Some("enum") => write_enum(out, name, ty, path),
+            other => panic!(
+                "{}: 类型 {} 的 kind {:?} 不支持",
+                path.display(),
+                name,
+                other
+            ),
+        }
+    }
+
+    let mut variants = Vec::new();
+    for instruction in &instructions {
+        let snake = to_snake_case(instruction["name"].as_str().unwrap());
+        let pascal = to_pascal_case(&snake);
+        let args_name = format!("{}Args", pascal);
+        if types.contains_key(args_name.as_str()) {
+            panic!(
+                "{}: 参数类型名 {} 与 IDL 自定义类型冲突",
+                path.display(),
+                args_name
+            );
+        }
+        write_struct(out, &args_name, &instruction["args"], path);
+        variants.push((
+            pascal,
+            args_name,
+            discriminator(instruction, "global", &snake),
+        ));
+    }
+
+    writeln!(out, "    /// 按 discriminator 解码的指令参数").unwrap();
+    writeln!(out, "    #[derive(Debug, Clone, PartialEq)]").unwrap();
+    writeln!(out, "    pub enum Instruction {{").unwrap();
+    for (pascal, args_name, _) in &variants {
+        writeln!(out, "        {}({}),", pascal, args_name).unwrap();
+    }
+    writeln!(out, "    }}\n").unwrap();
+    writeln!(out, "    impl Instruction {{").unwrap();
+    writeln!(
+        out,
+        "        /// data 含 8 字节 discriminator；未知指令或参数不完整时返回 None"
+    )
+    .unwrap();
+    writeln!(out, "        pub fn decode(data: &[u8]) -> Option<Self> {{").unwrap();
+    writeln!(out, "            let mut args = data.get(8..)?;").unwrap();
+    writeln!(out, "            match data.get(0..8)? {{").unwrap();
+    for (pascal, args_name, discriminator) in &variants {
+        writeln!(
+            out,
+            "                {:?} => Some(Self::{}({}::read(&mut args)?)),",
+            discriminator, pascal, args_name
+        )
+        .unwrap();
+    }
+    writeln!(out, "                _ => None,").unwrap();
+    writeln!(out, "            }}").unwrap();
+    writeln!(out, "        }}").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}\n").unwrap();
+}
+
+/// 递归收集类型引用的自定义类型
+fn collect_defined(
+    ty: &Value,
+    types: &BTreeMap<&str, &Value>,
+    used: &mut BTreeSet<String>,
+    path: &Path,
+) {
+    if let Some(name) = defined_name(ty) {
+        let def = types
+            .get(name)
+            .unwrap_or_else(|| panic!("{}: 找不到自定义类型 {}", path.display(), name));
+        if !used.insert(name.to_string()) {
+            return;
+        }
+        let def = &def["type"];
+        for field in def["fields"].as_array().into_iter().flatten() {
+            collect_defined(field_type(field), types, used, path);
+        }
+        for variant in def["variants"].as_array().into_iter().flatten() {
+            for field in variant["fields"].as_array().into_iter().flatten() {
+                collect_defined(field_type(field), types, used, path);
+            }
+        }
+        return;
+    }
+    for inner in [&ty["option"], &ty["vec"], &ty["array"][0]] {
+        if !inner.is_null() {
+            collect_defined(inner, types, used, path);
+        }
+    }
+}
+
+/// 自定义类型名: Anchor >= 0.30 为 {"defined": {"name": ..}}，旧格式为 {"defined": ".."}
+fn defined_name(ty: &Value) -> Option<&str> {
+    ty["defined"]["name"]
+        .as_str()
+        .or_else(|| ty["defined"].as_str())
+}
+
+/// 具名字段为 {"name", "type"}，元组字段直接是类型
+fn field_type(field: &Value) -> &Value {
+    if field["name"].is_string() {
+        &field["type"]
+    } else {
+        field
+    }
+}
+
+/// 生成结构体 (具名字段或元组) 及其 Borsh 解码
+fn write_struct(out: &mut String, name: &str, fields: &Value, path: &Path) {
+    let fields: Vec<&Value> = fields.as_array().into_iter().flatten().collect();
+    let named = fields.iter().all(|field| field["name"].is_string());
+
+    writeln!(out, "    #[derive(Debug, Clone, PartialEq)]").unwrap();
+    if fields.is_empty() {
+        writeln!(out, "    pub struct {};\n", name).unwrap();
+    } else if named {
+        writeln!(out, "    pub struct {} {{", name).unwrap();
+        for field in &fields {
+            writeln!(
+                out,
+                "        pub {}: {},",
+                field_name(field),
+                rust_type(&field["type"], path)
+            )
+            .unwrap();
+        }
+        writeln!(out, "    }}\n").unwrap();
+    } else {
+        let types: Vec<String> = fields
+            .iter()
+            .map(|ty| format!("pub {}", rust_type(ty, path)))
+            .collect();
+        writeln!(out, "    pub struct {}({});\n", name, types.join(", ")).unwrap();
+    }
+
+    writeln!(out, "    impl BorshRead for {} {{", name).unwrap();
+    writeln!(out, "        fn read(data: &mut &[u8]) -> Option<Self> {{").unwrap();
+    writeln!(out, "            Some({})", construct("Self", &fields)).unwrap();
+    writeln!(out, "        }}").unwrap();
+    writeln!(out, "    }}\n").unwrap();
+}
+
+/// 生成枚举 (Borsh: 1 字节 variant 序号 + 字段) 及其解码
+fn write_enum(out: &mut String, name: &str, ty: &Value, path: &Path) {
+    let variants: Vec<&Value> = ty["variants"].as_array().into_iter().flatten().collect();
+
+    writeln!(out, "    #[derive(Debug, Clone, PartialEq)]").unwrap();
+    writeln!(out, "    pub enum {} {{", name).unwrap();
+    for variant in &variants {
+        let variant_name = variant["name"].as_str().unwrap();
+        let fields: Vec<&Value> = variant["fields"].as_array().into_iter().flatten().collect();
+        if fields.is_empty() {
+            writeln!(out, "        {},", variant_name).unwrap();
+        } else if fields.iter().all(|field| field["name"].is_string()) {
+            let fields: Vec<String> = fields
+                .iter()
+                .map(|field| format!("{}: {}", field_name(field), rust_type(&field["type"], path)))
+                .collect();
+            writeln!(out, "        {} {{ {} }},", variant_name, fields.join(", ")).unwrap();
+        } else {
+            let types: Vec<String> = fields.iter().map(|ty| rust_type(ty, path)).collect();
+            writeln!(out, "        {}({}),", variant_name, types.join(", ")).unwrap();
+        }
+    }
+    writeln!(out, "    }}\n").unwrap();
+
+    writeln!(out, "    impl BorshRead for {} {{", name).unwrap();
+    writeln!(out, "        fn read(data: &mut &[u8]) -> Option<Self> {{").unwrap();
+    writeln!(out, "            match u8::read(data)? {{").unwrap();
+    for (index, variant) in variants.iter().enumerate() {
+        let fields: Vec<&Value> = variant["fields"].as_array().into_iter().flatten().collect();
+        let constructor = format!("Self::{}", variant["name"].as_str().unwrap());
+        writeln!(
+            out,
+            "                {} => Some({}),",
+            index,
+            construct(&constructor, &fields)
+        )
+        .unwrap();
+    }
+    writeln!(out, "                _ => None,").unwrap();
+    writeln!(out, "            }}").unwrap();
+    writeln!(out, "        }}").unwrap();
+    writeln!(out, "    }}\n").unwrap();
+}
+
+/// 按字段顺序逐个 Borsh 解码的构造表达式
+fn construct(constructor: &str, fields: &[&Value]) -> String {
+    if fields.is_empty() {
+        return constructor.to_string();
+    }
+    if fields.iter().all(|field| field["name"].is_string()) {
+        let fields: Vec<String> = fields
+            .iter()
+            .map(|field| format!("{}: BorshRead::read(data)?", field_name(field)))
+            .collect();
+        format!("{} {{ {} }}", constructor, fields.join(", "))
+    } else {
+        let fields: Vec<&str> = fields.iter().map(|_| "BorshRead::read(data)?").collect();
+        format!("{}({})", constructor, fields.join(", "))
+    }
+}
+
+/// IDL 字段名转 Rust 字段名 (snake_case，关键字加 r#)
+fn field_name(field: &Value) -> String {
+    let name = to_snake_case(field["name"].as_str().unwrap());
+    match name.as_str() {
+        "type" | "match" | "move" | "ref" | "self" | "struct" | "enum" | "fn" | "mod" | "use" => {
+            format!("r#{}", name)
+        }
+        _ => name,
+    }
+}
+
+/// IDL 类型转 Rust 类型
+fn rust_type(ty: &Value, path: &Path) -> String {
+    if let Some(primitive) = ty.as_str() {
+        return match primitive {
+            "bool" | "u8" | "i8" | "u16" | "i16" | "u32" | "i32" | "u64" | "i64" | "u128"
+            | "i128" | "f32" | "f64" => primitive.to_string(),
+            "pubkey" | "publicKey" => "[u8; 32]".to_string(),
+            "string" => "String".to_string(),
+            "bytes" => "Vec<u8>".to_string(),
+            other => panic!("{}: 不支持的类型 {}", path.display(), other),
+        };
+    }
+    if let Some(name) = defined_name(ty) {
+        return name.to_string();
+    }
+    if !ty["option"].is_null() {
+        return format!("Option<{}>", rust_type(&ty["option"], path));
+    }
+    if !ty["vec"].is_null() {
+        return format!("Vec<{}>", rust_type(&ty["vec"], path));
+    }
+    if let Some(len) = ty["array"][1].as_u64() {
+        return format!("[{}; {}]", rust_type(&ty["array"][0], path), len);
+    }
+    panic!("{}: 不支持的类型 {}", path.display(), ty)
+}
+
+/// snake_case 转 PascalCase (swap_exact_out -> SwapExactOut)
+fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// 旧格式 IDL 的指令名为 camelCase (swapExactOut)，统一转为 snake_case
+fn to_snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
@@ -0,0 +1,239 @@
+use crate::idl::{amm, cp_amm, dynamic_bonding_curve, lb_clmm};
+use crate::pb::meteora::SwapIntent;
+
+/// SwapParameters2.swap_mode (DAMM V2 / DBC)
+const SWAP_MODE_EXACT_IN: u8 = 0;
+const SWAP_MODE_PARTIAL_FILL: u8 = 1;
+const SWAP_MODE_EXACT_OUT: u8 = 2;
+
+/// Swap 指令参数中的交易意图
+#[derive(Default)]
+pub struct SwapArgs {
+    pub intent: SwapIntent,
+    /// exact_in: 输入数量；exact_out: 期望输出数量
+    pub requested_amount: u64,
+    /// exact_in: 最少输出 (min_amount_out)；exact_out: 最多输入 (max_in_amount)；0 表示未设置
+    pub slippage_bound: u64,
+    /// swap_with_price_impact 的最大价格冲击 (bps)
+    pub max_price_impact_bps: u32,
+}
+
+impl SwapArgs {
+    fn exact_in(amount_in: u64, minimum_amount_out: u64) -> Self {
+        Self {
+            intent: SwapIntent::IntentExactIn,
+            requested_amount: amount_in,
+            slippage_bound: minimum_amount_out,
+            max_price_impact_bps: 0,
+        }
+    }
+
+    fn exact_out(out_amount: u64, max_in_amount: u64) -> Self {
+        Self {
+            intent: SwapIntent::IntentExactOut,
+            requested_amount: out_amount,
+            slippage_bound: max_in_amount,
+            max_price_impact_bps: 0,
+        }
+    }
+
+    fn price_impact(amount_in: u64, max_price_impact_bps: u16) -> Self {
+        Self {
+            intent: SwapIntent::IntentExactIn,
+            requested_amount: amount_in,
+            slippage_bound: 0,
+            max_price_impact_bps: max_price_impact_bps as u32,
+        }
+    }
+
+    /// SwapParameters2: ExactIn / PartialFill 时 amount_0 = 输入, amount_1 = 最少输出；
+    /// ExactOut 时 amount_0 = 输出, amount_1 = 最多输入
+    fn swap_mode(amount_0: u64, amount_1: u64, swap_mode: u8) -> Option<Self> {
+        let intent = match swap_mode {
+            SWAP_MODE_EXACT_IN => SwapIntent::IntentExactIn,
+            SWAP_MODE_PARTIAL_FILL => SwapIntent::IntentPartialFill,
+            SWAP_MODE_EXACT_OUT => SwapIntent::IntentExactOut,
+            _ => return None,
+        };
+        Some(Self {
+            intent,
+            requested_amount: amount_0,
+            slippage_bound: amount_1,
+            max_price_impact_bps: 0,
+        })
+    }
+}
+
+/// 按程序 ID 用 IDL 生成的解码器 (见 build.rs) 解码 Swap 指令参数 (data 含 8 字节 discriminator)
+/// 非 Swap 指令或参数不完整时返回 None
+/// `_` 分支在检入的 IDL 只含 Swap 指令时不可达，换成上游完整 IDL 后匹配其余指令
+pub fn decode_swap_args(program_id: &[u8], data: &[u8]) -> Option<SwapArgs> {
+    if program_id == lb_clmm::PROGRAM_ID.0.as_slice() {
+        use lb_clmm::Instruction;
+        return match Instruction::decode(data)? {
+            Instruction::Swap(args) => {
+                Some(SwapArgs::exact_in(args.amount_in, args.min_amount_out))
+            }
+            Instruction::Swap2(args) => {
+                Some(SwapArgs::exact_in(args.amount_in, args.min_amount_out))
+            }
+            Instruction::SwapExactOut(args) => {
+                Some(SwapArgs::exact_out(args.out_amount, args.max_in_amount))
+            }
+            Instruction::SwapExactOut2(args) => {
+                Some(SwapArgs::exact_out(args.out_amount, args.max_in_amount))
+            }
+            Instruction::SwapWithPriceImpact(args) => Some(SwapArgs::price_impact(
+                args.amount_in,
+                args.max_price_impact_bps,
+            )),
+            Instruction::SwapWithPriceImpact2(args) => Some(SwapArgs::price_impact(
+                args.amount_in,
+                args.max_price_impact_bps,
+            )),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        };
+    }
+
+    if program_id == cp_amm::PROGRAM_ID.0.as_slice() {
+        use cp_amm::Instruction;
+        return match Instruction::decode(data)? {
+            Instruction::Swap(args) => Some(SwapArgs::exact_in(
+                args.params.amount_in,
+                args.params.minimum_amount_out,
+            )),
+            Instruction::Swap2(args) => SwapArgs::swap_mode(
+                args.params.amount_0,
+                args.params.amount_1,
+                args.params.swap_mode,
+            ),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        };
+    }
+
+    if program_id == dynamic_bonding_curve::PROGRAM_ID.0.as_slice() {
+        use dynamic_bonding_curve::Instruction;
+        return match Instruction::decode(data)? {
+            Instruction::Swap(args) => Some(SwapArgs::exact_in(
+                args.params.amount_in,
+                args.params.minimum_amount_out,
+            )),
+            Instruction::Swap2(args) => SwapArgs::swap_mode(
+                args.params.amount_0,
+                args.params.amount_1,
+                args.params.swap_mode,
+            ),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        };
+    }
+
+    if program_id == amm::PROGRAM_ID.0.as_slice() {
+        use amm::Instruction;
+        return match Instruction::decode(data)? {
+            Instruction::Swap(args) => {
+                Some(SwapArgs::exact_in(args.in_amount, args.minimum_out_amount))
+            }
+            _ => None,
+        };
+    }
+
+    None
+}
+
+/// 实际成交距离滑点边界的余量 (bps)
+/// exact_in: (实际输出 - 最少输出) / 实际输出；exact_out: (最多输入 - 实际输入) / 实际输入
+/// 未设置边界或金额缺失时返回 None
+pub fn slippage_headroom_bps(
+    args: &SwapArgs,
+    input_amount: u64,
+    output_amount: u64,
+) -> Option<u32> {
+    if args.slippage_bound == 0 {
+        return None;
+    }
+
+    let (executed, margin) = match args.intent {
+        SwapIntent::IntentExactIn | SwapIntent::IntentPartialFill => (
+            output_amount,
+            output_amount.checked_sub(args.slippage_bound)?,
+        ),
+        SwapIntent::IntentExactOut => {
+            (input_amount, args.slippage_bound.checked_sub(input_amount)?)
+        }
+        SwapIntent::IntentUnknown => return None,
+    };
+    if executed == 0 {
+        return None;
+    }
+
+    u32::try_from(margin as u128 * 10_000 / executed as u128).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idl::{IdlInstruction, CP_AMM_INSTRUCTIONS, LB_CLMM_INSTRUCTIONS};
+
+    /// discriminator + 依次拼接的 u64 参数
+    fn instruction_data(table: &[IdlInstruction], name: &str, amounts: &[u64]) -> Vec<u8> {
+        let mut data = table
+            .iter()
+            .find(|instruction| instruction.name == name)
+            .map(|instruction| instruction.discriminator.to_vec())
+            .unwrap();
+        for amount in amounts {
+            data.extend_from_slice(&amount.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn decodes_dlmm_exact_out() {
+        // SwapExactOutArgs 字段顺序: max_in_amount, out_amount
+        let data = instruction_data(LB_CLMM_INSTRUCTIONS, "swap_exact_out", &[1_050, 1_000]);
+        let args = decode_swap_args(lb_clmm::PROGRAM_ID.0.as_slice(), &data).unwrap();
+        assert_eq!(args.intent, SwapIntent::IntentExactOut);
+        assert_eq!(args.requested_amount, 1_000);
+        assert_eq!(args.slippage_bound, 1_050);
+
+        // 指令属于其他程序时不解码
+        assert!(decode_swap_args(&[0u8; 32], &data).is_none());
+    }
+
+    #[test]
+    fn decodes_damm_v2_swap_modes() {
+        let program_id = cp_amm::PROGRAM_ID.0.as_slice();
+        let swap2 = |swap_mode: u8| {
+            let mut data = instruction_data(CP_AMM_INSTRUCTIONS, "swap2", &[500, 480]);
+            data.push(swap_mode);
+            decode_swap_args(program_id, &data)
+        };
+
+        assert_eq!(swap2(0).unwrap().intent, SwapIntent::IntentExactIn);
+        assert_eq!(swap2(1).unwrap().intent, SwapIntent::IntentPartialFill);
+        let exact_out = swap2(2).unwrap();
+        assert_eq!(exact_out.intent, SwapIntent::IntentExactOut);
+        assert_eq!(exact_out.requested_amount, 500);
+        assert_eq!(exact_out.slippage_bound, 480);
+        assert!(swap2(3).is_none());
+    }
+
+    #[test]
+    fn measures_slippage_headroom() {
+        let exact_in = SwapArgs::exact_in(1_000, 950);
+        assert_eq!(slippage_headroom_bps(&exact_in, 1_000, 1_000), Some(500));
+        // 实际输出低于边界 (不应发生) 或输出为 0
+        assert_eq!(slippage_headroom_bps(&exact_in, 1_000, 900), None);
+        assert_eq!(slippage_headroom_bps(&exact_in, 1_000, 0), None);
+
+        let exact_out = SwapArgs::exact_out(1_000, 1_100);
+        assert_eq!(slippage_headroom_bps(&exact_out, 1_000, 1_000), Some(1_000));
+
+        // 未设置边界
+        let price_impact = SwapArgs::price_impact(1_000, 50);
+        assert_eq!(slippage_headroom_bps(&price_impact, 1_000, 1_000), None);
+    }
+}
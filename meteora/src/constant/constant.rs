@@ -1,3 +1,4 @@
+use crate::pb::meteora::RouteSource;
 use substreams_solana::b58;
 use substreams_solana_utils::pubkey::Pubkey;
 
@@ -17,68 +18,91 @@ pub const METEORA_DAMM_V2_PROGRAM_ID: Pubkey =
 pub const METEORA_DBC_PROGRAM_ID: Pubkey =
     Pubkey(b58!("dbcij3LWUppWqq96dh6gJWwBifmcGfLSB5D4DuSMaqN"));
 
+/// DAMM V1 (Dynamic AMM) - 资金存放在 Dynamic Vault 中，池子只持有 vault LP
+pub const METEORA_DAMM_V1_PROGRAM_ID: Pubkey =
+    Pubkey(b58!("Eo7WjKq67rjJQSZxS6z3YkapzY3eMj6Xy8X5EQVn5UaB"));
+
+/// Dynamic Vault - DAMM V1 池子通过 deposit / withdraw CPI 存取资金 (不单独输出 Swap)
+pub const METEORA_VAULT_PROGRAM_ID: Pubkey =
+    Pubkey(b58!("24Uqj9JCLxUeoC3hGfh5W3s9FM9uCHDS2SG3LYwBpyTi"));
+
 /// 程序 ID 与平台名称映射表
+/// 修改时需同步更新 substreams.yaml 中 `meteora` 模块 blockFilter 的 query
 pub const FILTER_PROGRAM_IDS: &[(Pubkey, &str)] = &[
     (METEORA_DLMM_PROGRAM_ID, "meteora_dlmm"),
     (METEORA_DAMM_V2_PROGRAM_ID, "meteora_damm"),
     (METEORA_DBC_PROGRAM_ID, "meteora_dbc"),
+    (METEORA_DAMM_V1_PROGRAM_ID, "meteora_damm_v1"),
 ];
 
 // ============================================================================
-// DLMM Swap Discriminators (LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo)
-// Pool 地址: accounts[0] (lb_pair)
+// DAMM V1 流动性指令 (IDL 指令名)
+// 账户: pool (0), lp_mint (1), user_pool_lp (2), ..., a_token_vault (9), b_token_vault (10)
 // ============================================================================
 
-/// swap - 基础 Swap 方法
-/// args: amount_in (u64), min_amount_out (u64)
-pub const DLMM_SWAP: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+/// 添加流动性: add_balance_liquidity / add_imbalance_liquidity
+pub const DAMM_V1_DEPOSIT_INSTRUCTIONS: &[&str] =
+    &["add_balance_liquidity", "add_imbalance_liquidity"];
+
+/// 移除流动性: remove_balance_liquidity / remove_liquidity_single_side
+pub const DAMM_V1_WITHDRAW_INSTRUCTIONS: &[&str] =
+    &["remove_balance_liquidity", "remove_liquidity_single_side"];
+
+// ============================================================================
+// Dynamic Vault 指令 (IDL 指令名)
+// 账户: vault (0), token_vault (1), lp_mint (2), user_token (3), user_lp (4), user (5)
+// ============================================================================
 
-/// swap2 - Swap 改进版，支持 memo 和更多 bin
-/// args: amount_in (u64), min_amount_out (u64), remaining_accounts_info
-pub const DLMM_SWAP2: [u8; 8] = [65, 75, 63, 76, 235, 91, 91, 136];
+/// deposit - 用户 token 存入 token_vault，args: token_amount (u64), minimum_lp_token_amount (u64)
+pub const VAULT_DEPOSIT: &str = "deposit";
 
-/// swap_exact_out - 精确输出金额的 Swap
-/// args: max_in_amount (u64), out_amount (u64)
-pub const DLMM_SWAP_EXACT_OUT: [u8; 8] = [250, 73, 101, 33, 38, 207, 75, 184];
+/// withdraw - 销毁 vault LP 取回 token，args: unmint_amount (u64), min_out_amount (u64)
+pub const VAULT_WITHDRAW: &str = "withdraw";
 
-/// swap_exact_out2 - 精确输出改进版
-/// args: max_in_amount (u64), out_amount (u64), remaining_accounts_info
-pub const DLMM_SWAP_EXACT_OUT2: [u8; 8] = [43, 215, 247, 132, 137, 60, 243, 81];
+// ============================================================================
+// Token-2022 (用于识别转账手续费与 transfer hook)
+// ============================================================================
 
-/// swap_with_price_impact - 带价格影响保护的 Swap
-/// args: amount_in (u64), active_id (Option<i32>), max_price_impact_bps (u16)
-pub const DLMM_SWAP_WITH_PRICE_IMPACT: [u8; 8] = [56, 173, 230, 208, 173, 228, 156, 205];
+/// Token-2022 (Base58，用于和 TokenBalance.program_id 比对)
+pub const TOKEN_2022_PROGRAM: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
 
-/// swap_with_price_impact2 - 价格影响保护改进版
-/// args: amount_in (u64), active_id (Option<i32>), max_price_impact_bps (u16), remaining_accounts_info
-pub const DLMM_SWAP_WITH_PRICE_IMPACT2: [u8; 8] = [74, 98, 192, 214, 177, 51, 75, 51];
+/// Transfer Hook 接口 Execute 指令: sha256("spl-transfer-hook-interface:execute")[..8]
+/// Token-2022 转账时以 CPI 调用 hook 程序
+pub const TRANSFER_HOOK_EXECUTE: [u8; 8] = [105, 37, 101, 197, 75, 251, 102, 26];
 
 // ============================================================================
-// DAMM V2 Swap Discriminators (cpamdpZCGKUy5JxQXB4dcpGPiikHawvSWAd6mEn1sGG)
-// Pool 地址: accounts[1] (pool), accounts[0] 是固定的 pool_authority
+// Mints
 // ============================================================================
 
-/// swap - DAMM V2 基础 Swap 方法
-/// args: params (SwapParameters)
-/// 注意: discriminator 与 DLMM swap 相同 (Anchor 方法名相同)
-pub const DAMM_SWAP: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+/// WSOL (原始字节，用于和账户列表比对)
+pub const WSOL_MINT_ID: Pubkey = Pubkey(b58!("So11111111111111111111111111111111111111112"));
 
-/// swap2 - DAMM V2 Swap 改进版
-/// args: params (SwapParameters2)
-/// 注意: discriminator 与 DLMM swap2 相同 (Anchor 方法名相同)
-pub const DAMM_SWAP2: [u8; 8] = [65, 75, 63, 76, 235, 91, 91, 136];
+/// SOL 精度
+pub const SOL_DECIMALS: u32 = 9;
 
 // ============================================================================
-// DBC Swap Discriminators (dbcij3LWUppWqq96dh6gJWwBifmcGfLSB5D4DuSMaqN)
-// Pool 地址: accounts[2] (pool), accounts[0] 是 pool_authority, accounts[1] 是 config
+// 聚合器 / Bot Program IDs (用于路由归因)
 // ============================================================================
 
-/// swap - DBC 基础 Swap 方法 (用于 Trading Bots)
-/// args: params (SwapParameters)
-/// 注意: discriminator 与 DLMM/DAMM swap 相同 (Anchor 方法名相同)
-pub const DBC_SWAP: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+/// Jupiter Aggregator v6
+pub const JUPITER_V6_PROGRAM_ID: Pubkey =
+    Pubkey(b58!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4"));
+
+/// OKX DEX Router
+pub const OKX_DEX_ROUTER_PROGRAM_ID: Pubkey =
+    Pubkey(b58!("6m2CDdhRgxpH4WjvdzxAYbGxwdGUz5MziiL5jek2kBma"));
 
-/// swap2 - DBC Swap 改进版
-/// args: params (SwapParameters2)
-/// 注意: discriminator 与 DLMM/DAMM swap2 相同 (Anchor 方法名相同)
-pub const DBC_SWAP2: [u8; 8] = [65, 75, 63, 76, 235, 91, 91, 136];
+/// Raydium 路由
+pub const RAYDIUM_ROUTE_PROGRAM_ID: Pubkey =
+    Pubkey(b58!("routeUGWgWzqBWFcrCfv8tritsqukccJPu3q5GPP3xS"));
+
+/// Photon 交易 Bot
+pub const PHOTON_PROGRAM_ID: Pubkey = Pubkey(b58!("BSfD6SHZigAfDWSjzD5Q41jw8LmKwtmjskPH9XW1mrRW"));
+
+/// 外层程序 ID 与路由来源映射表
+pub const ROUTE_PROGRAM_IDS: &[(Pubkey, RouteSource)] = &[
+    (JUPITER_V6_PROGRAM_ID, RouteSource::RouteJupiter),
+    (OKX_DEX_ROUTER_PROGRAM_ID, RouteSource::RouteOkx),
+    (RAYDIUM_ROUTE_PROGRAM_ID, RouteSource::RouteRaydium),
+    (PHOTON_PROGRAM_ID, RouteSource::RouteBot),
+];
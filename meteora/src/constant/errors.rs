@@ -0,0 +1,213 @@
+// ============================================================================
+// 交易错误名称表 (bincode 枚举序号 / 自定义错误码 -> 名称)
+// 用于解码失败交易的 TransactionError
+// ============================================================================
+
+/// TransactionError 变体 (按 bincode 枚举序号)
+pub const TRANSACTION_ERRORS: &[&str] = &[
+    "AccountInUse",
+    "AccountLoadedTwice",
+    "AccountNotFound",
+    "ProgramAccountNotFound",
+    "InsufficientFundsForFee",
+    "InvalidAccountForFee",
+    "AlreadyProcessed",
+    "BlockhashNotFound",
+    "InstructionError",
+    "CallChainTooDeep",
+    "MissingSignatureForFee",
+    "InvalidAccountIndex",
+    "SignatureFailure",
+    "InvalidProgramForExecution",
+    "SanitizeFailure",
+    "ClusterMaintenance",
+    "AccountBorrowOutstanding",
+    "WouldExceedMaxBlockCostLimit",
+    "UnsupportedVersion",
+    "InvalidWritableAccount",
+    "WouldExceedMaxAccountCostLimit",
+    "WouldExceedAccountDataBlockLimit",
+    "TooManyAccountLocks",
+    "AddressLookupTableNotFound",
+    "InvalidAddressLookupTableOwner",
+    "InvalidAddressLookupTableData",
+    "InvalidAddressLookupTableIndex",
+    "InvalidRentPayingAccount",
+    "WouldExceedMaxVoteCostLimit",
+    "WouldExceedAccountDataTotalLimit",
+    "DuplicateInstruction",
+    "InsufficientFundsForRent",
+    "MaxLoadedAccountsDataSizeExceeded",
+    "InvalidLoadedAccountsDataSizeLimit",
+    "ResanitizationNeeded",
+    "ProgramExecutionTemporarilyRestricted",
+    "UnbalancedTransaction",
+    "ProgramCacheHitMaxLimit",
+];
+
+/// TransactionError::InstructionError 的 bincode 序号
+pub const TRANSACTION_ERROR_INSTRUCTION: u32 = 8;
+
+/// InstructionError 变体 (按 bincode 枚举序号)
+pub const INSTRUCTION_ERRORS: &[&str] = &[
+    "GenericError",
+    "InvalidArgument",
+    "InvalidInstructionData",
+    "InvalidAccountData",
+    "AccountDataTooSmall",
+    "InsufficientFunds",
+    "IncorrectProgramId",
+    "MissingRequiredSignature",
+    "AccountAlreadyInitialized",
+    "UninitializedAccount",
+    "UnbalancedInstruction",
+    "ModifiedProgramId",
+    "ExternalAccountLamportSpend",
+    "ExternalAccountDataModified",
+    "ReadonlyLamportChange",
+    "ReadonlyDataModified",
+    "DuplicateAccountIndex",
+    "ExecutableModified",
+    "RentEpochModified",
+    "NotEnoughAccountKeys",
+    "AccountDataSizeChanged",
+    "AccountNotExecutable",
+    "AccountBorrowFailed",
+    "AccountBorrowOutstanding",
+    "DuplicateAccountOutOfSync",
+    "Custom",
+    "InvalidError",
+    "ExecutableDataModified",
+    "ExecutableLamportChange",
+    "ExecutableAccountNotRentExempt",
+    "UnsupportedProgramId",
+    "CallDepth",
+    "MissingAccount",
+    "ReentrancyNotAllowed",
+    "MaxSeedLengthExceeded",
+    "InvalidSeeds",
+    "InvalidRealloc",
+    "ComputationalBudgetExceeded",
+    "PrivilegeEscalation",
+    "ProgramEnvironmentSetupFailure",
+    "ProgramFailedToComplete",
+    "ProgramFailedToCompile",
+    "Immutable",
+    "IncorrectAuthority",
+    "BorshIoError",
+    "AccountNotRentExempt",
+    "InvalidAccountOwner",
+    "ArithmeticOverflow",
+    "UnsupportedSysvar",
+    "IllegalOwner",
+    "MaxAccountsDataAllocationsExceeded",
+    "MaxAccountsResizeExceeded",
+    "MaxInstructionTraceLengthExceeded",
+    "BuiltinProgramsMustConsumeComputeUnits",
+];
+
+/// InstructionError::Custom(u32) 的 bincode 序号
+pub const INSTRUCTION_ERROR_CUSTOM: u32 = 25;
+
+/// Anchor 框架错误码 (所有 Anchor 程序共享, 100 ~ 5999)
+pub const ANCHOR_ERRORS: &[(u32, &str)] = &[
+    (100, "InstructionMissing"),
+    (101, "InstructionFallbackNotFound"),
+    (102, "InstructionDidNotDeserialize"),
+    (103, "InstructionDidNotSerialize"),
+    (2000, "ConstraintMut"),
+    (2001, "ConstraintHasOne"),
+    (2002, "ConstraintSigner"),
+    (2003, "ConstraintRaw"),
+    (2004, "ConstraintOwner"),
+    (2005, "ConstraintRentExempt"),
+    (2006, "ConstraintSeeds"),
+    (2012, "ConstraintAddress"),
+    (2014, "ConstraintTokenMint"),
+    (2015, "ConstraintTokenOwner"),
+    (3000, "AccountDiscriminatorAlreadySet"),
+    (3001, "AccountDiscriminatorNotFound"),
+    (3002, "AccountDiscriminatorMismatch"),
+    (3003, "AccountDidNotDeserialize"),
+    (3004, "AccountDidNotSerialize"),
+    (3005, "AccountNotEnoughKeys"),
+    (3006, "AccountNotMutable"),
+    (3007, "AccountOwnedByWrongProgram"),
+    (3008, "InvalidProgramId"),
+    (3009, "InvalidProgramExecutable"),
+    (3010, "AccountNotSigner"),
+    (3011, "AccountNotSystemOwned"),
+    (3012, "AccountNotInitialized"),
+    (3013, "AccountNotProgramData"),
+    (3014, "AccountNotAssociatedTokenAccount"),
+    (3015, "AccountSysvarMismatch"),
+    (3016, "AccountReallocExceedsLimit"),
+    (3017, "AccountDuplicateReallocs"),
+];
+
+/// DLMM 程序错误码 (6000 起)
+pub const DLMM_ERRORS: &[(u32, &str)] = &[
+    (6000, "InvalidStartBinIndex"),
+    (6001, "InvalidBinId"),
+    (6002, "InvalidInput"),
+    (6003, "ExceededAmountSlippageTolerance"),
+    (6004, "ExceededBinSlippageTolerance"),
+    (6005, "CompositionFactorFlawed"),
+    (6006, "NonPresetBinStep"),
+    (6007, "ZeroLiquidity"),
+    (6008, "InvalidPosition"),
+    (6009, "BinArrayNotFound"),
+    (6010, "InvalidTokenMint"),
+    (6011, "InvalidAccountForSingleDeposit"),
+    (6012, "PairInsufficientLiquidity"),
+    (6013, "InvalidFeeOwner"),
+    (6014, "InvalidFeeWithdrawAmount"),
+    (6015, "InvalidAdmin"),
+    (6016, "IdenticalFeeOwner"),
+    (6017, "InvalidBps"),
+    (6018, "MathOverflow"),
+    (6019, "TypeCastFailed"),
+];
+
+/// DAMM V2 程序错误码 (6000 起)
+pub const DAMM_V2_ERRORS: &[(u32, &str)] = &[
+    (6000, "MathOverflow"),
+    (6001, "InvalidFee"),
+    (6002, "ExceededSlippage"),
+    (6003, "PoolDisabled"),
+    (6004, "ExceedMaxFeeBps"),
+    (6005, "InvalidAdmin"),
+    (6006, "AmountIsZero"),
+    (6007, "TypeCastFailed"),
+];
+
+/// DBC 程序错误码 (6000 起)
+pub const DBC_ERRORS: &[(u32, &str)] = &[
+    (6000, "MathOverflow"),
+    (6001, "InvalidFee"),
+    (6002, "ExceededSlippage"),
+];
+
+/// SPL Token / Token-2022 错误码
+pub const TOKEN_ERRORS: &[(u32, &str)] = &[
+    (0, "NotRentExempt"),
+    (1, "InsufficientFunds"),
+    (2, "InvalidMint"),
+    (3, "MintMismatch"),
+    (4, "OwnerMismatch"),
+    (5, "FixedSupply"),
+    (6, "AlreadyInUse"),
+    (7, "InvalidNumberOfProvidedSigners"),
+    (8, "InvalidNumberOfRequiredSigners"),
+    (9, "UninitializedState"),
+    (10, "NativeNotSupported"),
+    (11, "NonNativeHasBalance"),
+    (12, "InvalidInstruction"),
+    (13, "InvalidState"),
+    (14, "Overflow"),
+    (15, "AuthorityTypeNotSupported"),
+    (16, "MintCannotFreeze"),
+    (17, "AccountFrozen"),
+    (18, "MintDecimalsMismatch"),
+    (19, "NonNativeNotSupported"),
+];
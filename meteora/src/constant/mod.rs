@@ -1,19 +1,33 @@
 pub mod constant;
+pub mod errors;
 
 pub use constant::{
-    // DAMM Discriminators
-    DAMM_SWAP,
-    DAMM_SWAP2,
-    // DBC Discriminators
-    DBC_SWAP,
-    DBC_SWAP2,
-    // DLMM Discriminators
-    DLMM_SWAP,
-    DLMM_SWAP2,
-    DLMM_SWAP_EXACT_OUT,
-    DLMM_SWAP_EXACT_OUT2,
-    DLMM_SWAP_WITH_PRICE_IMPACT,
-    DLMM_SWAP_WITH_PRICE_IMPACT2,
+    // DAMM V1 流动性指令
+    DAMM_V1_DEPOSIT_INSTRUCTIONS,
+    DAMM_V1_WITHDRAW_INSTRUCTIONS,
     // Program IDs
     FILTER_PROGRAM_IDS,
+    METEORA_DAMM_V1_PROGRAM_ID,
+    METEORA_DAMM_V2_PROGRAM_ID,
+    METEORA_DBC_PROGRAM_ID,
+    METEORA_DLMM_PROGRAM_ID,
+    METEORA_VAULT_PROGRAM_ID,
+    ROUTE_PROGRAM_IDS,
+    // Mints
+    SOL_DECIMALS,
+    TOKEN_2022_PROGRAM,
+    TRANSFER_HOOK_EXECUTE,
+    // Dynamic Vault 指令
+    VAULT_DEPOSIT,
+    VAULT_WITHDRAW,
+    WSOL_MINT_ID,
+};
+
+pub use dex_common::constant::{
+    SYSTEM_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID, WSOL_MINT,
+};
+
+pub use errors::{
+    ANCHOR_ERRORS, DAMM_V2_ERRORS, DBC_ERRORS, DLMM_ERRORS, INSTRUCTION_ERRORS,
+    INSTRUCTION_ERROR_CUSTOM, TOKEN_ERRORS, TRANSACTION_ERRORS, TRANSACTION_ERROR_INSTRUCTION,
 };
@@ -0,0 +1,305 @@
+use crate::constant::{
+    DAMM_V1_DEPOSIT_INSTRUCTIONS, DAMM_V1_WITHDRAW_INSTRUCTIONS, METEORA_DAMM_V1_PROGRAM_ID,
+    METEORA_VAULT_PROGRAM_ID, VAULT_DEPOSIT, VAULT_WITHDRAW, WSOL_MINT,
+};
+use crate::idl::instruction_name;
+use crate::pb::meteora::{LiquidityAction, LiquidityEvent, ParseStatus, SwapSide};
+use crate::{checked_amount, SwapAmounts};
+use dex_common::account::AccountKeys;
+use dex_common::balance::{account_mint, token_amount};
+use dex_common::instruction::{flatten_instructions, get_child_instructions, FlattenedInstruction};
+use dex_common::transfer::parse_token_transfer;
+use substreams_solana::pb::sf::solana::r#type::v1::{
+    ConfirmedTransaction, TokenBalance, TransactionStatusMeta,
+};
+
+/// swap 账户: a_token_vault (5), b_token_vault (6)
+const SWAP_TOKEN_VAULT_POSITIONS: [usize; 2] = [5, 6];
+
+/// 流动性指令账户: lp_mint (1), user_pool_lp (2), a_token_vault (9), b_token_vault (10)
+const LIQUIDITY_LP_MINT_POSITION: usize = 1;
+const LIQUIDITY_USER_LP_POSITION: usize = 2;
+const LIQUIDITY_TOKEN_VAULT_POSITIONS: [usize; 2] = [9, 10];
+
+/// Dynamic Vault deposit / withdraw 账户: token_vault (1), user_token (3)
+const VAULT_TOKEN_VAULT_POSITION: usize = 1;
+const VAULT_USER_TOKEN_POSITION: usize = 3;
+
+// ============================================================================
+// Dynamic Vault: DAMM V1 指令内部的 deposit / withdraw CPI
+// ============================================================================
+
+/// 一次 Dynamic Vault deposit / withdraw
+struct VaultCall {
+    action: LiquidityAction,
+    token_vault: u32,
+    user_token: u32,
+    /// user_token 与 token_vault 之间实际转移的数量 (取自该 CPI 自身的 Token 转账)
+    amount: u64,
+}
+
+/// 解析 DAMM V1 指令子指令中的 Dynamic Vault deposit / withdraw
+/// deposit 统计 user_token -> token_vault 的转账，withdraw 统计 token_vault -> user_token 的转账
+fn vault_calls(account_keys: &AccountKeys, children: &[FlattenedInstruction]) -> Vec<VaultCall> {
+    let mut calls = Vec::new();
+
+    for (position, child) in children.iter().enumerate() {
+        let program_id = match account_keys.get(child.program_id_index as usize) {
+            Some(id) if id == METEORA_VAULT_PROGRAM_ID.0.as_slice() => id,
+            _ => continue,
+        };
+        let action = match instruction_name(program_id, child.data) {
+            Some(VAULT_DEPOSIT) => LiquidityAction::ActionDeposit,
+            Some(VAULT_WITHDRAW) => LiquidityAction::ActionWithdraw,
+            _ => continue,
+        };
+        let (token_vault, user_token) = match (
+            child.accounts.get(VAULT_TOKEN_VAULT_POSITION),
+            child.accounts.get(VAULT_USER_TOKEN_POSITION),
+        ) {
+            (Some(&token_vault), Some(&user_token)) => (token_vault as u32, user_token as u32),
+            _ => continue,
+        };
+        let (source, destination) = match action {
+            LiquidityAction::ActionDeposit => (user_token, token_vault),
+            _ => (token_vault, user_token),
+        };
+
+        let amount = get_child_instructions(children, position)
+            .iter()
+            .filter_map(|transfer| {
+                account_keys
+                    .get(transfer.program_id_index as usize)
+                    .and_then(|id| parse_token_transfer(id, transfer.accounts, transfer.data))
+            })
+            .filter(|transfer| transfer.source == source && transfer.destination == destination)
+            .fold(0u64, |total, transfer| {
+                total.saturating_add(transfer.amount)
+            });
+
+        calls.push(VaultCall {
+            action,
+            token_vault,
+            user_token,
+            amount,
+        });
+    }
+
+    calls
+}
+
+// ============================================================================
+// Swap: 按 Dynamic Vault deposit / withdraw 计算金额
+// 池子只持有 vault LP，用户资金经 Dynamic Vault deposit / withdraw 进出 vault 的 token 账户
+// ============================================================================
+
+/// 输入 = 用户源账户转出总额 (经 vault deposit 存入 + 协议手续费)，输出 = vault withdraw 转入用户目标账户的数量
+/// mint 与精度取自 vault token 账户的余额记录 (vault 账户始终有记录，不受临时 WSOL 账户影响)
+/// 找不到 vault deposit / withdraw (如旧区块缺失内层指令) 时返回 Ok(None)，由调用方退化为余额差计算
+pub fn swap_amounts_from_vaults(
+    meta: &TransactionStatusMeta,
+    account_keys: &AccountKeys,
+    children: &[FlattenedInstruction],
+    inst_accounts: &[u8],
+    user_source: u32,
+    user_destination: u32,
+) -> Result<Option<SwapAmounts>, (ParseStatus, String)> {
+    let vaults = match resolve_positions(inst_accounts, SWAP_TOKEN_VAULT_POSITIONS) {
+        Some(vaults) => vaults,
+        None => return Ok(None),
+    };
+
+    let calls = vault_calls(account_keys, children);
+    let deposit = calls.iter().find(|call| {
+        call.action == LiquidityAction::ActionDeposit
+            && call.user_token == user_source
+            && vaults.contains(&call.token_vault)
+    });
+    let withdraw = calls.iter().find(|call| {
+        call.action == LiquidityAction::ActionWithdraw
+            && call.user_token == user_destination
+            && vaults.contains(&call.token_vault)
+    });
+    let (deposit, withdraw) = match (deposit, withdraw) {
+        (Some(deposit), Some(withdraw)) => (deposit, withdraw),
+        _ => return Ok(None),
+    };
+
+    // 协议手续费由用户源账户直接转出，不经过 vault，因此输入按源账户的全部转出计算
+    let input_amount: i128 = children
+        .iter()
+        .filter_map(|child| {
+            account_keys
+                .get(child.program_id_index as usize)
+                .and_then(|id| parse_token_transfer(id, child.accounts, child.data))
+        })
+        .filter(|transfer| transfer.source == user_source)
+        .map(|transfer| transfer.amount as i128)
+        .sum();
+    let output_amount = withdraw.amount as i128;
+
+    let (input, output) = match (
+        account_mint(meta, deposit.token_vault),
+        account_mint(meta, withdraw.token_vault),
+    ) {
+        (Some(input), Some(output)) => (input, output),
+        _ => return Ok(None),
+    };
+    let ((input_mint, input_decimals), (output_mint, output_decimals)) = (input, output);
+    let input_amount = checked_amount(input_amount, &input_mint)?;
+    let output_amount = checked_amount(output_amount, &output_mint)?;
+
+    // 与临时 WSOL 场景一致: 收到 SOL 为卖出，其余按买入 (收到的一方为 base)
+    let amounts = if output_mint == WSOL_MINT {
+        SwapAmounts {
+            base_mint: input_mint,
+            quote_mint: output_mint,
+            base_amount: input_amount,
+            quote_amount: output_amount,
+            base_decimals: input_decimals,
+            quote_decimals: output_decimals,
+            side: SwapSide::SideSell,
+            ..Default::default()
+        }
+    } else {
+        SwapAmounts {
+            base_mint: output_mint,
+            quote_mint: input_mint,
+            base_amount: output_amount,
+            quote_amount: input_amount,
+            base_decimals: output_decimals,
+            quote_decimals: input_decimals,
+            side: SwapSide::SideBuy,
+            ..Default::default()
+        }
+    };
+
+    Ok(Some(amounts))
+}
+
+// ============================================================================
+// 流动性: add_* / remove_* 指令，金额取自 Dynamic Vault deposit / withdraw
+// ============================================================================
+
+/// 解析交易中的 DAMM V1 添加 / 移除流动性指令
+pub fn parse_liquidity_events(
+    tx: &ConfirmedTransaction,
+    slot: u64,
+    timestamp_ms: u64,
+    tx_index: u32,
+) -> Vec<LiquidityEvent> {
+    let mut events = Vec::new();
+    let (transaction, meta) = match (tx.transaction.as_ref(), tx.meta.as_ref()) {
+        (Some(transaction), Some(meta)) => (transaction, meta),
+        _ => return events,
+    };
+    let message = match transaction.message.as_ref() {
+        Some(message) => message,
+        None => return events,
+    };
+
+    let account_keys = AccountKeys::new(message, meta);
+    let instructions = flatten_instructions(message, meta);
+
+    for (position, inst) in instructions.iter().enumerate() {
+        let program_id = match account_keys.get(inst.program_id_index as usize) {
+            Some(id) if id == METEORA_DAMM_V1_PROGRAM_ID.0.as_slice() => id,
+            _ => continue,
+        };
+        let name = match instruction_name(program_id, inst.data) {
+            Some(name) => name,
+            None => continue,
+        };
+        let action = if DAMM_V1_DEPOSIT_INSTRUCTIONS.contains(&name) {
+            LiquidityAction::ActionDeposit
+        } else if DAMM_V1_WITHDRAW_INSTRUCTIONS.contains(&name) {
+            LiquidityAction::ActionWithdraw
+        } else {
+            continue;
+        };
+
+        let vaults = match resolve_positions(inst.accounts, LIQUIDITY_TOKEN_VAULT_POSITIONS) {
+            Some(vaults) => vaults,
+            None => continue,
+        };
+
+        // 子指令中存入 / 取出 a、b token vault 的 Dynamic Vault deposit / withdraw
+        let mut amounts = [0u64; 2];
+        for call in vault_calls(
+            &account_keys,
+            get_child_instructions(&instructions, position),
+        ) {
+            for (amount, vault) in amounts.iter_mut().zip(vaults) {
+                if call.token_vault == vault {
+                    *amount = amount.saturating_add(call.amount);
+                }
+            }
+        }
+
+        let [(token_a_mint, token_a_decimals), (token_b_mint, token_b_decimals)] =
+            vaults.map(|vault| account_mint(meta, vault).unwrap_or_default());
+
+        // 池子 LP 数量: 用户 LP 账户的余额变化 (铸造或销毁)
+        let lp_amount = inst
+            .accounts
+            .get(LIQUIDITY_USER_LP_POSITION)
+            .map(|&index| balance_change(meta, index as u32))
+            .unwrap_or(0);
+
+        let encode = |position: usize| {
+            inst.accounts
+                .get(position)
+                .and_then(|&index| account_keys.get(index as usize))
+                .map(|key| bs58::encode(key).into_string())
+                .unwrap_or_default()
+        };
+
+        events.push(LiquidityEvent {
+            pool: encode(0),
+            signature: transaction
+                .signatures
+                .first()
+                .map(|s| bs58::encode(s).into_string())
+                .unwrap_or_default(),
+            user: account_keys
+                .get(0)
+                .map(|signer| bs58::encode(signer).into_string())
+                .unwrap_or_default(),
+            platform: "meteora_damm_v1".to_string(),
+            timestamp_ms,
+            slot,
+            tx_index,
+            instruction_index: inst.instruction_index,
+            inner_instruction_index: inst.inner_instruction_index,
+            action: action.into(),
+            instruction: name.to_string(),
+            token_a_mint,
+            token_b_mint,
+            token_a_amount: amounts[0],
+            token_b_amount: amounts[1],
+            token_a_decimals,
+            token_b_decimals,
+            lp_mint: encode(LIQUIDITY_LP_MINT_POSITION),
+            lp_amount,
+        });
+    }
+
+    events
+}
+
+/// 指令 accounts 中 a / b token vault 的账户索引
+fn resolve_positions(inst_accounts: &[u8], positions: [usize; 2]) -> Option<[u32; 2]> {
+    Some([
+        *inst_accounts.get(positions[0])? as u32,
+        *inst_accounts.get(positions[1])? as u32,
+    ])
+}
+
+/// Token 账户余额变化的绝对值
+fn balance_change(meta: &TransactionStatusMeta, account_index: u32) -> u64 {
+    let find = |balances: &[TokenBalance]| {
+        token_amount(balances.iter().find(|b| b.account_index == account_index))
+    };
+    let change = find(&meta.post_token_balances) - find(&meta.pre_token_balances);
+    u64::try_from(change.unsigned_abs()).unwrap_or(u64::MAX)
+}
@@ -0,0 +1,433 @@
+use crate::constant::METEORA_DLMM_PROGRAM_ID;
+use crate::idl::event;
+use crate::pb::meteora::{DlmmEventKind, DlmmPairEvent, DlmmPairState};
+use dex_common::account::AccountKeys;
+use dex_common::instruction::{flatten_instructions, FlattenedInstruction};
+use std::collections::HashMap;
+use substreams::store::{StoreGet, StoreGetInt64, StoreSet, StoreSetInt64};
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+
+/// 由单 bin 成交反推 bin_step 时允许的最大估计误差 (bin_step 单位)
+const BIN_STEP_TOLERANCE: f64 = 0.1;
+
+/// DLMM bin_step 取值上限 (bps)
+const MAX_BIN_STEP: f64 = 1000.0;
+
+/// DLMM 合约常量: BASIS_POINT_MAX
+const BASIS_POINT_MAX: u64 = 10_000;
+
+/// 动态费率 (volatility_accumulator * bin_step)^2 * variable_fee_control 换算到 1e9 精度费率的除数
+const VARIABLE_FEE_SCALE: u128 = 100_000_000_000;
+
+/// 1e9 精度费率换算为 bps 的除数
+const FEE_RATE_PER_BPS: f64 = 100_000.0;
+
+// ============================================================================
+// store_meteora_dlmm_pairs 的字段 (key: `<lb_pair>:<field>`，值为 int64，后写覆盖)
+// ============================================================================
+
+const FIELD_ACTIVE_ID: &str = "active_id";
+const FIELD_LAST_SWAP_TIMESTAMP_MS: &str = "last_swap_timestamp_ms";
+const FIELD_BIN_STEP: &str = "bin_step";
+const FIELD_BASE_FACTOR: &str = "base_factor";
+const FIELD_PROTOCOL_SHARE: &str = "protocol_share";
+const FIELD_FILTER_PERIOD: &str = "filter_period";
+const FIELD_DECAY_PERIOD: &str = "decay_period";
+const FIELD_REDUCTION_FACTOR: &str = "reduction_factor";
+const FIELD_VARIABLE_FEE_CONTROL: &str = "variable_fee_control";
+const FIELD_MAX_VOLATILITY_ACCUMULATOR: &str = "max_volatility_accumulator";
+
+fn pair_key(lb_pair: &str, field: &str) -> String {
+    format!("{}:{}", lb_pair, field)
+}
+
+// ============================================================================
+// Swap 事件
+// ============================================================================
+
+/// DLMM Swap 事件中用到的字段
+pub struct DlmmSwap {
+    pub start_bin_id: i32,
+    pub end_bin_id: i32,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub swap_for_y: bool,
+    pub fee: u64,
+    pub protocol_fee: u64,
+    pub fee_bps: u128,
+    pub host_fee: u64,
+}
+
+/// 解码 Swap 事件
+/// 布局: lb_pair (32) + from (32) + start_bin_id (i32) + end_bin_id (i32) + amount_in (u64)
+///       + amount_out (u64) + swap_for_y (bool) + fee (u64) + protocol_fee (u64) + fee_bps (u128) + host_fee (u64)
+fn parse_swap(data: &[u8]) -> Option<DlmmSwap> {
+    Some(DlmmSwap {
+        start_bin_id: i32::from_le_bytes(data.get(64..68)?.try_into().ok()?),
+        end_bin_id: i32::from_le_bytes(data.get(68..72)?.try_into().ok()?),
+        amount_in: u64::from_le_bytes(data.get(72..80)?.try_into().ok()?),
+        amount_out: u64::from_le_bytes(data.get(80..88)?.try_into().ok()?),
+        swap_for_y: *data.get(88)? != 0,
+        fee: u64::from_le_bytes(data.get(89..97)?.try_into().ok()?),
+        protocol_fee: u64::from_le_bytes(data.get(97..105)?.try_into().ok()?),
+        fee_bps: u128::from_le_bytes(data.get(105..121)?.try_into().ok()?),
+        host_fee: u64::from_le_bytes(data.get(121..129)?.try_into().ok()?),
+    })
+}
+
+/// 在 Swap 子指令中查找 DLMM 的 Swap 事件 (emit_cpi!)
+pub fn find_dlmm_swap(
+    account_keys: &AccountKeys,
+    children: &[FlattenedInstruction],
+) -> Option<DlmmSwap> {
+    children.iter().find_map(|child| {
+        let program_id = account_keys.get(child.program_id_index as usize)?;
+        if program_id != METEORA_DLMM_PROGRAM_ID.0.as_slice() {
+            return None;
+        }
+        match event(program_id, child.data)? {
+            ("Swap", data) => parse_swap(data),
+            _ => None,
+        }
+    })
+}
+
+/// bin_step 不在交易数据中，由只成交在单个 bin 内的 Swap 反推:
+/// 扣除手续费后的成交价即该 bin 的价格 (1 + bin_step / 10000) ^ bin_id
+/// 跨 bin、bin_id 为 0、金额过小导致估计误差过大时无法推断，返回 None
+pub fn infer_bin_step(swap: &DlmmSwap) -> Option<u32> {
+    if swap.start_bin_id != swap.end_bin_id || swap.end_bin_id == 0 {
+        return None;
+    }
+
+    let amount_in = swap.amount_in.checked_sub(swap.fee)? as f64;
+    let amount_out = swap.amount_out as f64;
+    if amount_in <= 0.0 || amount_out <= 0.0 {
+        return None;
+    }
+    let raw_y_per_x = if swap.swap_for_y {
+        amount_out / amount_in
+    } else {
+        amount_in / amount_out
+    };
+
+    // 金额取整带来的相对误差约为 1 / 较小金额，折算到 bin_step 后除以 |bin_id|
+    let bin_id = swap.end_bin_id as f64;
+    let estimate = (raw_y_per_x.powf(1.0 / bin_id) - 1.0) * 10_000.0;
+    let error = 10_000.0 / amount_in.min(amount_out) / bin_id.abs();
+    let rounded = estimate.round();
+
+    (error < BIN_STEP_TOLERANCE
+        && (estimate - rounded).abs() < BIN_STEP_TOLERANCE
+        && (1.0..=MAX_BIN_STEP).contains(&rounded))
+    .then_some(rounded as u32)
+}
+
+// ============================================================================
+// 池子事件: Swap / LbPairCreate / FeeParameterUpdate / DynamicFeeParameterUpdate / GoToABin
+// ============================================================================
+
+/// 解析交易中 DLMM 程序对自身的事件 CPI，按执行顺序返回
+pub fn parse_pair_events(
+    tx: &ConfirmedTransaction,
+    slot: u64,
+    timestamp_ms: u64,
+    tx_index: u32,
+) -> Vec<DlmmPairEvent> {
+    let mut events = Vec::new();
+    let (transaction, meta) = match (tx.transaction.as_ref(), tx.meta.as_ref()) {
+        (Some(transaction), Some(meta)) => (transaction, meta),
+        _ => return events,
+    };
+    let message = match transaction.message.as_ref() {
+        Some(message) => message,
+        None => return events,
+    };
+
+    let account_keys = AccountKeys::new(message, meta);
+
+    for (position, inst) in flatten_instructions(message, meta).iter().enumerate() {
+        let program_id = match account_keys.get(inst.program_id_index as usize) {
+            Some(id) if id == METEORA_DLMM_PROGRAM_ID.0.as_slice() => id,
+            _ => continue,
+        };
+        let (name, data) = match event(program_id, inst.data) {
+            Some(event) => event,
+            None => continue,
+        };
+        let lb_pair = match data.get(0..32) {
+            Some(lb_pair) => bs58::encode(lb_pair).into_string(),
+            None => continue,
+        };
+
+        let mut pair_event = DlmmPairEvent {
+            lb_pair,
+            signature: transaction
+                .signatures
+                .first()
+                .map(|s| bs58::encode(s).into_string())
+                .unwrap_or_default(),
+            timestamp_ms,
+            slot,
+            tx_index,
+            instruction_index: inst.instruction_index,
+            inner_instruction_index: inst.inner_instruction_index,
+            ordinal: ((tx_index as u64) << 32) | (position as u64 + 1),
+            ..Default::default()
+        };
+        if decode_pair_event(&mut pair_event, name, data).is_some() {
+            events.push(pair_event);
+        }
+    }
+
+    events
+}
+
+/// 按事件名填充 kind 与对应字段，数据长度不足或不是池子事件时返回 None
+fn decode_pair_event(pair_event: &mut DlmmPairEvent, name: &str, data: &[u8]) -> Option<()> {
+    let read_u16 = |offset: usize| -> Option<u32> {
+        Some(u16::from_le_bytes(data.get(offset..offset + 2)?.try_into().ok()?) as u32)
+    };
+    let read_u32 = |offset: usize| -> Option<u32> {
+        Some(u32::from_le_bytes(
+            data.get(offset..offset + 4)?.try_into().ok()?,
+        ))
+    };
+    let read_i32 = |offset: usize| -> Option<i32> {
+        Some(i32::from_le_bytes(
+            data.get(offset..offset + 4)?.try_into().ok()?,
+        ))
+    };
+
+    let kind = match name {
+        "Swap" => {
+            let swap = parse_swap(data)?;
+            pair_event.start_bin_id = swap.start_bin_id;
+            pair_event.end_bin_id = swap.end_bin_id;
+            pair_event.fee_bps = u64::try_from(swap.fee_bps).unwrap_or(u64::MAX);
+            pair_event.bin_step = infer_bin_step(&swap).unwrap_or(0);
+            DlmmEventKind::KindSwap
+        }
+        // lb_pair (32) + bin_step (u16) + token_x (32) + token_y (32)
+        "LbPairCreate" => {
+            pair_event.bin_step = read_u16(32)?;
+            DlmmEventKind::KindPairCreate
+        }
+        // lb_pair (32) + protocol_share (u16) + base_factor (u16)
+        "FeeParameterUpdate" => {
+            pair_event.protocol_share = read_u16(32)?;
+            pair_event.base_factor = read_u16(34)?;
+            DlmmEventKind::KindFeeParameterUpdate
+        }
+        // lb_pair (32) + filter_period (u16) + decay_period (u16) + reduction_factor (u16)
+        //   + variable_fee_control (u32) + max_volatility_accumulator (u32)
+        "DynamicFeeParameterUpdate" => {
+            pair_event.filter_period = read_u16(32)?;
+            pair_event.decay_period = read_u16(34)?;
+            pair_event.reduction_factor = read_u16(36)?;
+            pair_event.variable_fee_control = read_u32(38)?;
+            pair_event.max_volatility_accumulator = read_u32(42)?;
+            DlmmEventKind::KindDynamicFeeParameterUpdate
+        }
+        // lb_pair (32) + from_bin_id (i32) + to_bin_id (i32)
+        "GoToABin" => {
+            pair_event.start_bin_id = read_i32(32)?;
+            pair_event.end_bin_id = read_i32(36)?;
+            DlmmEventKind::KindGoToABin
+        }
+        _ => return None,
+    };
+    pair_event.kind = kind.into();
+
+    Some(())
+}
+
+// ============================================================================
+// 池子状态
+// ============================================================================
+
+/// 将事件携带的字段写入 store (以事件 ordinal 为写入序号)
+pub fn store_pair_event(store: &StoreSetInt64, pair_event: &DlmmPairEvent) {
+    let set = |field: &str, value: i64| {
+        store.set(
+            pair_event.ordinal,
+            pair_key(&pair_event.lb_pair, field),
+            &value,
+        )
+    };
+
+    match pair_event.kind() {
+        DlmmEventKind::KindSwap => {
+            set(FIELD_ACTIVE_ID, pair_event.end_bin_id as i64);
+            set(FIELD_LAST_SWAP_TIMESTAMP_MS, pair_event.timestamp_ms as i64);
+            // 反推成功的 bin_step 与 LbPairCreate 一致
+            if pair_event.bin_step > 0 {
+                set(FIELD_BIN_STEP, pair_event.bin_step as i64);
+            }
+        }
+        DlmmEventKind::KindPairCreate => set(FIELD_BIN_STEP, pair_event.bin_step as i64),
+        DlmmEventKind::KindFeeParameterUpdate => {
+            set(FIELD_BASE_FACTOR, pair_event.base_factor as i64);
+            set(FIELD_PROTOCOL_SHARE, pair_event.protocol_share as i64);
+        }
+        DlmmEventKind::KindDynamicFeeParameterUpdate => {
+            set(FIELD_FILTER_PERIOD, pair_event.filter_period as i64);
+            set(FIELD_DECAY_PERIOD, pair_event.decay_period as i64);
+            set(FIELD_REDUCTION_FACTOR, pair_event.reduction_factor as i64);
+            set(
+                FIELD_VARIABLE_FEE_CONTROL,
+                pair_event.variable_fee_control as i64,
+            );
+            set(
+                FIELD_MAX_VOLATILITY_ACCUMULATOR,
+                pair_event.max_volatility_accumulator as i64,
+            );
+        }
+        DlmmEventKind::KindGoToABin => set(FIELD_ACTIVE_ID, pair_event.end_bin_id as i64),
+        DlmmEventKind::KindUnknown => {}
+    }
+}
+
+/// 同一区块内上一笔 Swap 结束时的波动率状态
+/// store 无法读取自身，跨区块时只能在参考值被重置的 Swap 处重新确定
+#[derive(Clone, Copy, Default)]
+struct Volatility {
+    index_reference: Option<i32>,
+    volatility_reference: Option<u32>,
+    volatility_accumulator: Option<u32>,
+}
+
+/// 按执行顺序处理区块内的事件，active bin 发生移动时输出池子状态
+pub fn pair_states(events: &[DlmmPairEvent], pairs: &StoreGetInt64) -> Vec<DlmmPairState> {
+    let mut states = Vec::new();
+    let mut volatilities: HashMap<&str, Volatility> = HashMap::new();
+
+    for pair_event in events {
+        let kind = pair_event.kind();
+        if kind != DlmmEventKind::KindSwap && kind != DlmmEventKind::KindGoToABin {
+            continue;
+        }
+
+        let lb_pair = pair_event.lb_pair.as_str();
+        let field = |field: &str| pairs.get_at(pair_event.ordinal, pair_key(lb_pair, field));
+        let field_u32 = |name: &str| field(name).map(|value| value as u32).unwrap_or(0);
+
+        let mut state = DlmmPairState {
+            lb_pair: pair_event.lb_pair.clone(),
+            signature: pair_event.signature.clone(),
+            timestamp_ms: pair_event.timestamp_ms,
+            slot: pair_event.slot,
+            tx_index: pair_event.tx_index,
+            instruction_index: pair_event.instruction_index,
+            inner_instruction_index: pair_event.inner_instruction_index,
+            kind: kind.into(),
+            previous_active_id: pair_event.start_bin_id,
+            active_id: pair_event.end_bin_id,
+            bins_crossed: pair_event.start_bin_id.abs_diff(pair_event.end_bin_id),
+            // 写入序号之前的值，即上一笔 Swap
+            previous_swap_timestamp_ms: pair_event
+                .ordinal
+                .checked_sub(1)
+                .and_then(|ordinal| {
+                    pairs.get_at(ordinal, pair_key(lb_pair, FIELD_LAST_SWAP_TIMESTAMP_MS))
+                })
+                .map(|ts| ts as u64)
+                .unwrap_or(0),
+            bin_step: field_u32(FIELD_BIN_STEP),
+            base_factor: field_u32(FIELD_BASE_FACTOR),
+            protocol_share: field_u32(FIELD_PROTOCOL_SHARE),
+            fee_bps: pair_event.fee_bps,
+            filter_period: field_u32(FIELD_FILTER_PERIOD),
+            decay_period: field_u32(FIELD_DECAY_PERIOD),
+            reduction_factor: field_u32(FIELD_REDUCTION_FACTOR),
+            variable_fee_control: field_u32(FIELD_VARIABLE_FEE_CONTROL),
+            max_volatility_accumulator: field_u32(FIELD_MAX_VOLATILITY_ACCUMULATOR),
+            ..Default::default()
+        };
+        // 基础费率 = base_factor * bin_step * 10 (1e9 精度)
+        state.base_fee_bps =
+            (state.base_factor as u64 * state.bin_step as u64 * 10) as f64 / FEE_RATE_PER_BPS;
+
+        // GoToABin 不更新波动率，沿用上一笔 Swap 的状态
+        let volatility = if kind == DlmmEventKind::KindSwap {
+            let has_dynamic_fee = field(FIELD_FILTER_PERIOD).is_some();
+            let volatility = if has_dynamic_fee && state.previous_swap_timestamp_ms > 0 {
+                update_volatility(volatilities.get(lb_pair), &state)
+            } else {
+                Volatility::default()
+            };
+            volatilities.insert(lb_pair, volatility);
+            volatility
+        } else {
+            volatilities.get(lb_pair).copied().unwrap_or_default()
+        };
+        state.index_reference = volatility.index_reference;
+        state.volatility_reference = volatility.volatility_reference;
+        state.volatility_accumulator = volatility.volatility_accumulator;
+        if state.bin_step > 0 {
+            state.variable_fee_bps = volatility.volatility_accumulator.map(|accumulator| {
+                variable_fee_bps(accumulator, state.bin_step, state.variable_fee_control)
+            });
+        }
+
+        if state.bins_crossed > 0 {
+            states.push(state);
+        }
+    }
+
+    states
+}
+
+/// 按合约规则推算 Swap 结束时的波动率:
+/// 1. 距上一笔 Swap >= filter_period: index_reference 重置为移动前的 active bin，
+///    volatility_reference = 上一笔的 volatility_accumulator * reduction_factor / 10000 (>= decay_period 时为 0)
+/// 2. 否则沿用上一笔的参考值
+/// 3. volatility_accumulator = volatility_reference + |index_reference - active_id| * 10000，不超过上限
+///
+/// 上一笔 Swap 不在本区块时其状态未知，只有重置后的值可以确定
+fn update_volatility(previous: Option<&Volatility>, state: &DlmmPairState) -> Volatility {
+    let elapsed = state
+        .timestamp_ms
+        .saturating_sub(state.previous_swap_timestamp_ms)
+        / 1000;
+
+    let (index_reference, volatility_reference) = if elapsed >= state.filter_period as u64 {
+        let volatility_reference = if elapsed >= state.decay_period as u64 {
+            Some(0)
+        } else {
+            previous
+                .and_then(|p| p.volatility_accumulator)
+                .map(|va| (va as u64 * state.reduction_factor as u64 / BASIS_POINT_MAX) as u32)
+        };
+        (Some(state.previous_active_id), volatility_reference)
+    } else {
+        (
+            previous.and_then(|p| p.index_reference),
+            previous.and_then(|p| p.volatility_reference),
+        )
+    };
+
+    let volatility_accumulator = match (index_reference, volatility_reference) {
+        (Some(index_reference), Some(volatility_reference)) => {
+            let delta_id = index_reference.abs_diff(state.active_id) as u64;
+            Some(
+                (volatility_reference as u64 + delta_id * BASIS_POINT_MAX)
+                    .min(state.max_volatility_accumulator as u64) as u32,
+            )
+        }
+        _ => None,
+    };
+
+    Volatility {
+        index_reference,
+        volatility_reference,
+        volatility_accumulator,
+    }
+}
+
+/// 动态费率 = ceil((volatility_accumulator * bin_step)^2 * variable_fee_control / 1e11) (1e9 精度)，换算为 bps
+fn variable_fee_bps(volatility_accumulator: u32, bin_step: u32, variable_fee_control: u32) -> f64 {
+    let square = (volatility_accumulator as u128 * bin_step as u128).pow(2);
+    let rate = (square * variable_fee_control as u128).div_ceil(VARIABLE_FEE_SCALE);
+    rate as f64 / FEE_RATE_PER_BPS
+}
@@ -0,0 +1,128 @@
+use crate::args::decode_swap_args;
+use crate::pb::meteora::FailedSwap;
+use crate::tx_error::{
+    anchor_error_name, custom_error_name, decode_transaction_error, failed_program,
+};
+use crate::{get_pool_account_index, match_program};
+use dex_common::account::AccountKeys;
+use dex_common::instruction::flatten_instructions;
+use std::collections::HashSet;
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+
+/// 解析失败交易中的 Meteora Swap 指令，附带解码后的错误原因
+/// 失败交易的余额不变，不计算金额；内层指令可能不完整 (执行到出错为止)
+pub fn parse_failed_transaction(
+    tx: &ConfirmedTransaction,
+    slot: u64,
+    timestamp_ms: u64,
+    tx_index: u32,
+) -> Vec<FailedSwap> {
+    let mut failed_swaps = Vec::new();
+    let (transaction, meta) = match (tx.transaction.as_ref(), tx.meta.as_ref()) {
+        (Some(transaction), Some(meta)) => (transaction, meta),
+        _ => return failed_swaps,
+    };
+    let (message, err) = match (transaction.message.as_ref(), meta.err.as_ref()) {
+        (Some(message), Some(err)) => (message, err),
+        _ => return failed_swaps,
+    };
+
+    let account_keys = AccountKeys::new(message, meta);
+    let mut seen_pools: HashSet<&[u8]> = HashSet::new();
+    let mut decoded = None;
+
+    for inst in flatten_instructions(message, meta) {
+        let program_id = match account_keys.get(inst.program_id_index as usize) {
+            Some(id) => id,
+            None => continue,
+        };
+        let platform = match match_program(program_id) {
+            Some(platform) => platform,
+            None => continue,
+        };
+        if decode_swap_args(program_id, inst.data).is_none() {
+            continue;
+        }
+
+        let pool = inst
+            .accounts
+            .get(get_pool_account_index(platform))
+            .and_then(|&idx| account_keys.get(idx as usize))
+            .unwrap_or_default();
+        if !seen_pools.insert(pool) {
+            continue;
+        }
+
+        // 错误每笔交易只解码一次
+        let failed =
+            decoded.get_or_insert_with(|| decode_failure(err.err.as_slice(), &meta.log_messages));
+
+        failed_swaps.push(FailedSwap {
+            pool: bs58::encode(pool).into_string(),
+            signature: transaction
+                .signatures
+                .first()
+                .map(|s| bs58::encode(s).into_string())
+                .unwrap_or_default(),
+            user: account_keys
+                .get(0)
+                .map(|signer| bs58::encode(signer).into_string())
+                .unwrap_or_default(),
+            platform: platform.to_string(),
+            timestamp_ms,
+            slot,
+            tx_index,
+            instruction_index: inst.instruction_index,
+            inner_instruction_index: inst.inner_instruction_index,
+            transaction_error: failed.transaction_error.clone(),
+            failed_instruction_index: failed.instruction_index,
+            instruction_error: failed.instruction_error.clone(),
+            custom_code: failed.custom_code,
+            failed_program_id: failed.program_id.clone(),
+            error_name: failed.error_name.clone(),
+            failed_in_swap: failed.instruction_index == Some(inst.instruction_index),
+            compute_units_consumed: meta.compute_units_consumed.unwrap_or(0),
+            fee: meta.fee,
+        });
+    }
+
+    failed_swaps
+}
+
+/// 交易失败原因 (交易级，同一交易内的事件共享)
+struct Failure {
+    transaction_error: String,
+    instruction_index: Option<u32>,
+    instruction_error: String,
+    custom_code: Option<u32>,
+    program_id: String,
+    error_name: String,
+}
+
+/// 解码 TransactionError 并结合日志确定报错程序与错误名
+/// 错误名优先级: 按报错程序查错误码表 > Anchor 错误日志 > InstructionError / TransactionError 变体名
+fn decode_failure(err: &[u8], log_messages: &[String]) -> Failure {
+    let decoded = decode_transaction_error(err);
+    let program_id = failed_program(log_messages).unwrap_or_default();
+
+    let error_name = match decoded.custom_code {
+        Some(code) => bs58::decode(program_id)
+            .into_vec()
+            .ok()
+            .and_then(|id| custom_error_name(&id, code))
+            .or_else(|| anchor_error_name(log_messages))
+            .unwrap_or_default()
+            .to_string(),
+        None if !decoded.instruction_error.is_empty() => decoded.instruction_error.clone(),
+        None => decoded.transaction_error.clone(),
+    };
+
+    Failure {
+        transaction_error: decoded.transaction_error,
+        instruction_index: decoded.instruction_index,
+        instruction_error: decoded.instruction_error,
+        custom_code: decoded.custom_code,
+        program_id: program_id.to_string(),
+        error_name,
+    }
+}
@@ -0,0 +1,321 @@
+use crate::constant::METEORA_DAMM_V1_PROGRAM_ID;
+use crate::dlmm::find_dlmm_swap;
+use crate::idl::{event, log_event};
+use crate::logs::program_data_by_invocation;
+use crate::pb::meteora::{FeeAmounts, FeeScope, FeeSnapshot, SwapEvent};
+use dex_common::account::AccountKeys;
+use dex_common::instruction::FlattenedInstruction;
+use std::collections::HashMap;
+use substreams::store::{DeltaInt64, Deltas, StoreAdd, StoreAddInt64};
+use substreams_solana::pb::sf::solana::r#type::v1::TransactionStatusMeta;
+
+/// 一个 UTC 日的毫秒数
+const MS_PER_DAY: u64 = 86_400_000;
+
+// ============================================================================
+// 手续费 store 的字段 (key: `<维度>:<fee_mint>:<field>`，值为 int64，累加)
+// ============================================================================
+
+const FIELD_TOTAL_FEE: &str = "total_fee";
+const FIELD_LP_FEE: &str = "lp_fee";
+const FIELD_PROTOCOL_FEE: &str = "protocol_fee";
+const FIELD_PARTNER_FEE: &str = "partner_fee";
+const FIELD_REFERRAL_FEE: &str = "referral_fee";
+const FIELD_TRADING_FEE: &str = "trading_fee";
+
+/// Swap 事件中的手续费
+pub struct SwapFees {
+    pub amounts: FeeAmounts,
+    /// 手续费以输入 token 收取 (否则以输出 token 收取)
+    pub on_input: bool,
+}
+
+// ============================================================================
+// 解码: 各程序 Swap 事件中的手续费拆分
+// ============================================================================
+
+/// 按平台解码 Swap 指令产生的事件
+/// DLMM / DAMM V2 / DBC 为子指令中的 emit_cpi! 事件，DAMM V1 为日志中的 emit! 事件
+pub fn swap_fees(
+    meta: &TransactionStatusMeta,
+    account_keys: &AccountKeys,
+    instructions: &[FlattenedInstruction],
+    position: usize,
+    children: &[FlattenedInstruction],
+    platform: &str,
+) -> Option<SwapFees> {
+    match platform {
+        // 手续费以输入 token 收取; fee 已包含 protocol_fee 与 host_fee
+        "meteora_dlmm" => {
+            let swap = find_dlmm_swap(account_keys, children)?;
+            Some(SwapFees {
+                amounts: fee_amounts(FeeAmounts {
+                    lp_fee: swap
+                        .fee
+                        .saturating_sub(swap.protocol_fee)
+                        .saturating_sub(swap.host_fee),
+                    protocol_fee: swap.protocol_fee,
+                    referral_fee: swap.host_fee,
+                    ..Default::default()
+                }),
+                on_input: true,
+            })
+        }
+        // EvtSwap: pool (32) + trade_direction (u8) + has_referral (bool) + params (u64 + u64)
+        //   + swap_result: output_amount (u64) + next_sqrt_price (u128) + lp_fee + protocol_fee + partner_fee + referral_fee (u64)
+        // AtoB 以输出的 token B 收取; BtoA 取决于池子的 collect_fee_mode (事件中没有)，按 BothToken (输出 token) 处理
+        "meteora_damm" => {
+            let data = find_event(account_keys, children, "EvtSwap")?;
+            Some(SwapFees {
+                amounts: fee_amounts(FeeAmounts {
+                    lp_fee: read_u64(data, 74)?,
+                    protocol_fee: read_u64(data, 82)?,
+                    partner_fee: read_u64(data, 90)?,
+                    referral_fee: read_u64(data, 98)?,
+                    ..Default::default()
+                }),
+                on_input: false,
+            })
+        }
+        // EvtSwap: pool (32) + config (32) + trade_direction (u8) + has_referral (bool) + params (u64 + u64)
+        //   + swap_result: actual_input_amount (u64) + output_amount (u64) + next_sqrt_price (u128)
+        //   + trading_fee + protocol_fee + referral_fee (u64)
+        // 手续费始终以 quote token 收取: BaseToQuote (0) 在输出侧，QuoteToBase (1) 在输入侧
+        "meteora_dbc" => {
+            let data = find_event(account_keys, children, "EvtSwap")?;
+            Some(SwapFees {
+                amounts: fee_amounts(FeeAmounts {
+                    trading_fee: read_u64(data, 114)?,
+                    protocol_fee: read_u64(data, 122)?,
+                    referral_fee: read_u64(data, 130)?,
+                    ..Default::default()
+                }),
+                on_input: *data.get(64)? == 1,
+            })
+        }
+        // Swap: in_amount + out_amount + trade_fee + protocol_fee + host_fee (u64)，以输入 token 收取
+        "meteora_damm_v1" => {
+            let data = find_log_event(meta, account_keys, instructions, position, "Swap")?;
+            Some(SwapFees {
+                amounts: fee_amounts(FeeAmounts {
+                    lp_fee: read_u64(&data, 16)?,
+                    protocol_fee: read_u64(&data, 24)?,
+                    referral_fee: read_u64(&data, 32)?,
+                    ..Default::default()
+                }),
+                on_input: true,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// 补上 total_fee (各项之和)
+fn fee_amounts(mut amounts: FeeAmounts) -> FeeAmounts {
+    amounts.total_fee = [
+        amounts.lp_fee,
+        amounts.protocol_fee,
+        amounts.partner_fee,
+        amounts.referral_fee,
+        amounts.trading_fee,
+    ]
+    .iter()
+    .fold(0u64, |total, &fee| total.saturating_add(fee));
+    amounts
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(
+        data.get(offset..offset + 8)?.try_into().ok()?,
+    ))
+}
+
+/// 在 Swap 子指令中查找程序对自身的事件 CPI
+fn find_event<'a>(
+    account_keys: &AccountKeys,
+    children: &[FlattenedInstruction<'a>],
+    name: &str,
+) -> Option<&'a [u8]> {
+    children.iter().find_map(|child| {
+        let program_id = account_keys.get(child.program_id_index as usize)?;
+        match event(program_id, child.data)? {
+            (event_name, data) if event_name == name => Some(data),
+            _ => None,
+        }
+    })
+}
+
+/// 在日志中查找 DAMM V1 指令写出的事件: 该指令是交易中第 k 次调用 DAMM V1，对应日志中第 k 次 invoke
+fn find_log_event(
+    meta: &TransactionStatusMeta,
+    account_keys: &AccountKeys,
+    instructions: &[FlattenedInstruction],
+    position: usize,
+    name: &str,
+) -> Option<Vec<u8>> {
+    let program_id = METEORA_DAMM_V1_PROGRAM_ID.0.as_slice();
+    let invocation = instructions[..position]
+        .iter()
+        .filter(|inst| account_keys.get(inst.program_id_index as usize) == Some(program_id))
+        .count();
+
+    let invocations =
+        program_data_by_invocation(&meta.log_messages, &bs58::encode(program_id).into_string())?;
+    invocations
+        .get(invocation)?
+        .iter()
+        .find_map(|data| match log_event(program_id, data)? {
+            (event_name, payload) if event_name == name => Some(payload.to_vec()),
+            _ => None,
+        })
+}
+
+// ============================================================================
+// 累计: 按池子 / 平台 / UTC 日期写入 store，再由 deltas 生成 FeeSnapshot
+// ============================================================================
+
+/// 将 Swap 的手续费累加到 store，key 前缀由 scope 决定
+/// 没有手续费或手续费 token 未知 (金额解析失败) 时跳过
+pub fn add_fees(store: &StoreAddInt64, event: &SwapEvent, scope: FeeScope) {
+    let fees = match event.fees.as_ref() {
+        Some(fees) if fees.total_fee > 0 => fees,
+        _ => return,
+    };
+    // pubkey_encoding=raw 时 Base58 字段为空，统一以 Base58 作为 key
+    let encode = |encoded: &String, raw: &Vec<u8>| {
+        if raw.is_empty() {
+            encoded.clone()
+        } else {
+            bs58::encode(raw).into_string()
+        }
+    };
+    let fee_mint = if event.fee_in_quote {
+        encode(&event.quote_mint, &event.quote_mint_raw)
+    } else {
+        encode(&event.base_mint, &event.base_mint_raw)
+    };
+    if fee_mint.is_empty() {
+        return;
+    }
+
+    let prefix = match scope {
+        FeeScope::ScopePool => format!("{}:{}", encode(&event.pool, &event.pool_raw), fee_mint),
+        FeeScope::ScopePlatform => format!("{}:{}", event.platform, fee_mint),
+        FeeScope::ScopeDay => format!(
+            "{}:{}:{}",
+            utc_date(event.timestamp_ms),
+            event.platform,
+            fee_mint
+        ),
+        FeeScope::ScopeUnknown => return,
+    };
+
+    let ordinal = ((event.tx_index as u64) << 32)
+        | ((event.instruction_index as u64) << 16)
+        | event.inner_instruction_index as u64;
+    for (field, amount) in [
+        (FIELD_TOTAL_FEE, fees.total_fee),
+        (FIELD_LP_FEE, fees.lp_fee),
+        (FIELD_PROTOCOL_FEE, fees.protocol_fee),
+        (FIELD_PARTNER_FEE, fees.partner_fee),
+        (FIELD_REFERRAL_FEE, fees.referral_fee),
+        (FIELD_TRADING_FEE, fees.trading_fee),
+    ] {
+        if amount > 0 {
+            store.add(
+                ordinal,
+                format!("{}:{}", prefix, field),
+                i64::try_from(amount).unwrap_or(i64::MAX),
+            );
+        }
+    }
+}
+
+/// 将本区块的 store deltas 按 (维度, fee_mint) 合并为 FeeSnapshot，保持首次出现的顺序
+pub fn fee_snapshots(deltas: &Deltas<DeltaInt64>, scope: FeeScope) -> Vec<FeeSnapshot> {
+    let mut snapshots: Vec<FeeSnapshot> = Vec::new();
+    let mut positions: HashMap<&str, usize> = HashMap::new();
+
+    for delta in deltas.deltas.iter() {
+        let (prefix, field) = match delta.key.rsplit_once(':') {
+            Some(parts) => parts,
+            None => continue,
+        };
+        let position = match positions.get(prefix) {
+            Some(&position) => position,
+            None => {
+                let snapshot = match new_snapshot(prefix, scope) {
+                    Some(snapshot) => snapshot,
+                    None => continue,
+                };
+                snapshots.push(snapshot);
+                positions.insert(prefix, snapshots.len() - 1);
+                snapshots.len() - 1
+            }
+        };
+
+        let snapshot = &mut snapshots[position];
+        let change = delta.new_value.saturating_sub(delta.old_value).max(0) as u64;
+        if let Some(amount) = field_mut(snapshot.delta.get_or_insert_with(Default::default), field)
+        {
+            *amount = amount.saturating_add(change);
+        }
+        if let Some(total) = field_mut(snapshot.total.get_or_insert_with(Default::default), field) {
+            *total = delta.new_value.max(0) as u64;
+        }
+    }
+
+    snapshots
+}
+
+/// 按 scope 解析 key 前缀: pool:<mint> / platform:<mint> / day:platform:<mint>
+fn new_snapshot(prefix: &str, scope: FeeScope) -> Option<FeeSnapshot> {
+    let mut snapshot = FeeSnapshot {
+        scope: scope.into(),
+        ..Default::default()
+    };
+    let mut parts = prefix.split(':');
+    match scope {
+        FeeScope::ScopePool => snapshot.pool = parts.next()?.to_string(),
+        FeeScope::ScopePlatform => snapshot.platform = parts.next()?.to_string(),
+        FeeScope::ScopeDay => {
+            snapshot.day = parts.next()?.to_string();
+            snapshot.platform = parts.next()?.to_string();
+        }
+        FeeScope::ScopeUnknown => return None,
+    }
+    snapshot.fee_mint = parts.next()?.to_string();
+    Some(snapshot)
+}
+
+fn field_mut<'a>(amounts: &'a mut FeeAmounts, field: &str) -> Option<&'a mut u64> {
+    match field {
+        FIELD_TOTAL_FEE => Some(&mut amounts.total_fee),
+        FIELD_LP_FEE => Some(&mut amounts.lp_fee),
+        FIELD_PROTOCOL_FEE => Some(&mut amounts.protocol_fee),
+        FIELD_PARTNER_FEE => Some(&mut amounts.partner_fee),
+        FIELD_REFERRAL_FEE => Some(&mut amounts.referral_fee),
+        FIELD_TRADING_FEE => Some(&mut amounts.trading_fee),
+        _ => None,
+    }
+}
+
+/// 毫秒时间戳对应的 UTC 日期 (YYYY-MM-DD)，按公历换算 (Howard Hinnant civil_from_days)
+fn utc_date(timestamp_ms: u64) -> String {
+    let days = (timestamp_ms / MS_PER_DAY) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
@@ -0,0 +1,234 @@
+use substreams_solana::b58;
+use substreams_solana_utils::pubkey::Pubkey;
+
+/// IDL 中的一条指令
+pub struct IdlInstruction {
+    /// 指令名 (snake_case)
+    pub name: &'static str,
+    pub discriminator: [u8; 8],
+}
+
+/// IDL 中的一个事件 (Anchor emit_cpi! / emit!)
+pub struct IdlEvent {
+    pub name: &'static str,
+    pub discriminator: [u8; 8],
+}
+
+/// 一个程序的指令表与事件表
+pub struct IdlProgram {
+    pub program_id: Pubkey,
+    pub instructions: &'static [IdlInstruction],
+    pub events: &'static [IdlEvent],
+}
+
+/// Anchor emit_cpi! 事件指令的前缀 (EVENT_IX_TAG，小端序)，其后为 8 字节事件 discriminator + Borsh 数据
+const EVENT_IX_TAG: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+
+/// Borsh 解码 (小端序)，build.rs 为 IDL 中的指令参数与自定义类型生成实现
+/// 数据不足或取值非法时返回 None；末尾多余的字节忽略 (与 Anchor 一致)
+pub trait BorshRead: Sized {
+    fn read(data: &mut &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_borsh_read_le {
+    ($($ty:ty),*) => {
+        $(
+            impl BorshRead for $ty {
+                fn read(data: &mut &[u8]) -> Option<Self> {
+                    let (bytes, rest) = data.split_first_chunk::<{ std::mem::size_of::<$ty>() }>()?;
+                    *data = rest;
+                    Some(<$ty>::from_le_bytes(*bytes))
+                }
+            }
+        )*
+    };
+}
+
+impl_borsh_read_le!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64);
+
+impl BorshRead for bool {
+    fn read(data: &mut &[u8]) -> Option<Self> {
+        match u8::read(data)? {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+}
+
+impl<T: BorshRead> BorshRead for Option<T> {
+    fn read(data: &mut &[u8]) -> Option<Self> {
+        match u8::read(data)? {
+            0 => Some(None),
+            1 => Some(Some(T::read(data)?)),
+            _ => None,
+        }
+    }
+}
+
+impl<T: BorshRead> BorshRead for Vec<T> {
+    fn read(data: &mut &[u8]) -> Option<Self> {
+        let len = u32::read(data)? as usize;
+        // 长度来自链上数据，按剩余字节数限制预分配
+        let mut items = Vec::with_capacity(len.min(data.len()));
+        for _ in 0..len {
+            items.push(T::read(data)?);
+        }
+        Some(items)
+    }
+}
+
+impl<T: BorshRead, const N: usize> BorshRead for [T; N] {
+    fn read(data: &mut &[u8]) -> Option<Self> {
+        let mut items = Vec::with_capacity(N);
+        for _ in 0..N {
+            items.push(T::read(data)?);
+        }
+        items.try_into().ok()
+    }
+}
+
+impl BorshRead for String {
+    fn read(data: &mut &[u8]) -> Option<Self> {
+        String::from_utf8(Vec::<u8>::read(data)?).ok()
+    }
+}
+
+// build.rs 根据 idls/*.json 生成: 每个程序的 `<NAME>_INSTRUCTIONS` / `<NAME>_EVENTS`、汇总表 `IDL_PROGRAMS`，
+// 以及每个程序的参数类型模块 (如 `lb_clmm::Instruction::decode`)
+include!(concat!(env!("OUT_DIR"), "/idl.rs"));
+
+/// 按 (程序 ID, discriminator) 查找指令名
+/// 不同程序的 Anchor 指令同名时 discriminator 相同，必须先按程序区分
+pub fn instruction_name(program_id: &[u8], data: &[u8]) -> Option<&'static str> {
+    let discriminator = data.get(0..8)?;
+    IDL_PROGRAMS
+        .iter()
+        .find(|program| program.program_id.0.as_slice() == program_id)?
+        .instructions
+        .iter()
+        .find(|instruction| instruction.discriminator == discriminator)
+        .map(|instruction| instruction.name)
+}
+
+/// 解析程序对自身的事件 CPI，返回 (事件名, Borsh 数据)
+pub fn event<'a>(program_id: &[u8], data: &'a [u8]) -> Option<(&'static str, &'a [u8])> {
+    if data.get(0..8)? != EVENT_IX_TAG {
+        return None;
+    }
+    log_event(program_id, &data[8..])
+}
+
+/// 解析 emit! 写入日志的事件数据 (8 字节事件 discriminator + Borsh 数据)，返回 (事件名, Borsh 数据)
+pub fn log_event<'a>(program_id: &[u8], data: &'a [u8]) -> Option<(&'static str, &'a [u8])> {
+    let discriminator = data.get(0..8)?;
+    IDL_PROGRAMS
+        .iter()
+        .find(|program| program.program_id.0.as_slice() == program_id)?
+        .events
+        .iter()
+        .find(|event| event.discriminator == discriminator)
+        .map(|event| (event.name, &data[8..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read<T: BorshRead>(bytes: &[u8]) -> Option<(T, usize)> {
+        let mut data = bytes;
+        let value = T::read(&mut data)?;
+        Some((value, data.len()))
+    }
+
+    /// 指令表中按名称取 discriminator
+    fn discriminator(table: &[IdlInstruction], name: &str) -> Vec<u8> {
+        table
+            .iter()
+            .find(|instruction| instruction.name == name)
+            .map(|instruction| instruction.discriminator.to_vec())
+            .unwrap()
+    }
+
+    #[test]
+    fn reads_little_endian_primitives() {
+        assert_eq!(read::<u16>(&[0x34, 0x12, 0xff]), Some((0x1234, 1)));
+        assert_eq!(read::<i32>(&(-5i32).to_le_bytes()), Some((-5, 0)));
+        assert_eq!(read::<u128>(&u128::MAX.to_le_bytes()), Some((u128::MAX, 0)));
+        assert_eq!(read::<u64>(&[1, 2, 3]), None);
+    }
+
+    #[test]
+    fn reads_bool_and_option_tags() {
+        assert_eq!(read::<bool>(&[1]), Some((true, 0)));
+        assert_eq!(read::<bool>(&[2]), None);
+        assert_eq!(read::<Option<i32>>(&[0]), Some((None, 0)));
+        assert_eq!(read::<Option<i32>>(&[1, 7, 0, 0, 0]), Some((Some(7), 0)));
+        assert_eq!(read::<Option<i32>>(&[2, 7, 0, 0, 0]), None);
+    }
+
+    #[test]
+    fn reads_collections() {
+        assert_eq!(
+            read::<Vec<u16>>(&[2, 0, 0, 0, 1, 0, 2, 0]),
+            Some((vec![1, 2], 0))
+        );
+        assert_eq!(read::<[u8; 3]>(&[1, 2, 3, 4]), Some(([1, 2, 3], 1)));
+        assert_eq!(
+            read::<String>(&[2, 0, 0, 0, b'o', b'k']),
+            Some(("ok".to_string(), 0))
+        );
+        // 声明的长度超过剩余数据
+        assert_eq!(read::<Vec<u8>>(&[255, 255, 255, 255, 1]), None);
+        assert_eq!(read::<String>(&[1, 0, 0, 0, 0xff]), None);
+    }
+
+    #[test]
+    fn decodes_generated_instruction_args() {
+        let mut data = discriminator(LB_CLMM_INSTRUCTIONS, "swap2");
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&990u64.to_le_bytes());
+        // remaining_accounts_info: 一个 TransferHookY 切片，长度 2
+        data.extend_from_slice(&[1, 0, 0, 0, 1, 2]);
+
+        assert_eq!(
+            lb_clmm::Instruction::decode(&data),
+            Some(lb_clmm::Instruction::Swap2(lb_clmm::Swap2Args {
+                amount_in: 1_000,
+                min_amount_out: 990,
+                remaining_accounts_info: lb_clmm::RemainingAccountsInfo {
+                    slices: vec![lb_clmm::RemainingAccountsSlice {
+                        accounts_type: lb_clmm::AccountsType::TransferHookY,
+                        length: 2,
+                    }],
+                },
+            }))
+        );
+
+        // 参数不完整、枚举取值非法或未知 discriminator
+        assert_eq!(lb_clmm::Instruction::decode(&data[..20]), None);
+        let mut invalid = data.clone();
+        invalid[28] = 9;
+        assert_eq!(lb_clmm::Instruction::decode(&invalid), None);
+        assert_eq!(lb_clmm::Instruction::decode(&[0u8; 24]), None);
+    }
+
+    #[test]
+    fn looks_up_instruction_names_per_program() {
+        let swap = discriminator(CP_AMM_INSTRUCTIONS, "swap");
+        // DLMM 与 DAMM V2 的 swap 同名，discriminator 相同，按程序区分
+        assert_eq!(
+            instruction_name(cp_amm::PROGRAM_ID.0.as_slice(), &swap),
+            Some("swap")
+        );
+        assert_eq!(
+            instruction_name(lb_clmm::PROGRAM_ID.0.as_slice(), &swap),
+            Some("swap")
+        );
+        assert_eq!(instruction_name(&[0u8; 32], &swap), None);
+        assert_eq!(
+            instruction_name(cp_amm::PROGRAM_ID.0.as_slice(), &swap[..4]),
+            None
+        );
+    }
+}
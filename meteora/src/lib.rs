@@ -1,23 +1,248 @@
+mod args;
 mod constant;
+mod damm_v1;
+mod dlmm;
+mod failed;
+mod fees;
+mod idl;
+mod logs;
+mod params;
 #[allow(unused)]
 mod pb;
-
-use crate::constant::{
-    DAMM_SWAP, DAMM_SWAP2, DBC_SWAP, DBC_SWAP2, DLMM_SWAP, DLMM_SWAP2, DLMM_SWAP_EXACT_OUT,
-    DLMM_SWAP_EXACT_OUT2, DLMM_SWAP_WITH_PRICE_IMPACT, DLMM_SWAP_WITH_PRICE_IMPACT2,
-    FILTER_PROGRAM_IDS,
+mod pool_state;
+mod token2022;
+mod tx_error;
+mod wsol;
+
+use crate::args::{decode_swap_args, slippage_headroom_bps};
+use crate::constant::{FILTER_PROGRAM_IDS, ROUTE_PROGRAM_IDS, SOL_DECIMALS, WSOL_MINT};
+use crate::damm_v1::parse_liquidity_events;
+use crate::dlmm::{pair_states, parse_pair_events, store_pair_event};
+use crate::failed::parse_failed_transaction;
+use crate::fees::{add_fees, fee_snapshots, swap_fees};
+use crate::params::{InvalidEvents, Params, PubkeyEncoding};
+use crate::pool_state::{pool_state, price_before};
+use crate::token2022::{find_transfer_leg, TransferLeg};
+use crate::wsol::{find_temporary_wsol_accounts, swap_transfer_amount, TemporaryWsol};
+use dex_common::account::AccountKeys;
+use dex_common::balance::{account_mint, account_owner, token_amount};
+use dex_common::cost::{transaction_cost, TransactionCost};
+use dex_common::instruction::{flatten_instructions, get_child_instructions, FlattenedInstruction};
+use dex_common::transfer::parse_token_transfer;
+use pb::meteora::{
+    DlmmPairEvents, DlmmPairStates, FailedSwaps, FeeScope, FeeSnapshots, LiquidityEvents,
+    ParseStatus, RouteSource, SwapEvent, SwapEvents, SwapSide,
 };
-use pb::meteora::{SwapEvent, SwapEvents, SwapSide};
+use std::collections::BTreeSet;
+use substreams::errors::Error;
+use substreams::pb::sf::substreams::index::v1::Keys;
+use substreams::store::{DeltaInt64, Deltas, StoreAddInt64, StoreGetInt64, StoreSetInt64};
 use substreams_solana::pb::sf::solana::r#type::v1::{
-    Block, ConfirmedTransaction, Message, TransactionStatusMeta,
+    Block, ConfirmedTransaction, TokenBalance, TransactionStatusMeta,
 };
-use substreams_solana_utils::instruction::get_flattened_instructions;
+
+// ============================================================================
+// Block Index: 为每个区块输出 `program:<base58>` 键
+// 配合 `meteora` 模块的 blockFilter，引擎可直接跳过没有 Meteora 交易的区块
+// ============================================================================
+#[substreams::handlers::map]
+fn meteora_index(block: Block) -> Keys {
+    // BTreeSet 保证输出顺序确定
+    let mut program_ids: BTreeSet<&[u8]> = BTreeSet::new();
+
+    for tx in block.transactions.iter() {
+        let (message, meta) = match (
+            tx.transaction.as_ref().and_then(|t| t.message.as_ref()),
+            tx.meta.as_ref(),
+        ) {
+            (Some(message), Some(meta)) => (message, meta),
+            _ => continue,
+        };
+
+        // 程序 ID 可能来自 ALT，统一在完整账户列表中查找
+        let account_keys = AccountKeys::new(message, meta);
+
+        for inst in flatten_instructions(message, meta) {
+            if let Some(program_id) = account_keys.get(inst.program_id_index as usize) {
+                program_ids.insert(program_id);
+            }
+        }
+    }
+
+    Keys {
+        keys: program_ids
+            .into_iter()
+            .map(|id| format!("program:{}", bs58::encode(id).into_string()))
+            .collect(),
+    }
+}
 
 // ============================================================================
 // 主 Map Handler
 // ============================================================================
 #[substreams::handlers::map]
-fn meteora(block: Block) -> SwapEvents {
+fn meteora(params: String, block: Block) -> Result<SwapEvents, Error> {
+    let params = Params::parse(&params)?;
+    let keep_invalid = params.invalid_events == InvalidEvents::Emit;
+
+    Ok(collect_swap_events(&params, &block, |event| {
+        keep_invalid || event.parse_status == ParseStatus::StatusOk as i32
+    }))
+}
+
+// ============================================================================
+// 诊断 Map Handler: 只输出金额解析失败的事件 (参数 invalid_events=route 时)
+// ============================================================================
+#[substreams::handlers::map]
+fn meteora_diagnostics(params: String, block: Block) -> Result<SwapEvents, Error> {
+    let params = Params::parse(&params)?;
+    if params.invalid_events != InvalidEvents::Route {
+        return Ok(SwapEvents::default());
+    }
+
+    Ok(collect_swap_events(&params, &block, |event| {
+        event.parse_status != ParseStatus::StatusOk as i32
+    }))
+}
+
+// ============================================================================
+// 失败交易 Map Handler: 输出失败交易中的 Swap 指令与解码后的错误原因
+// ============================================================================
+#[substreams::handlers::map]
+fn meteora_failed_swaps(block: Block) -> FailedSwaps {
+    let slot = block.slot;
+    let timestamp_ms = block
+        .block_time
+        .as_ref()
+        .map(|t| t.timestamp as u64 * 1000)
+        .unwrap_or(0);
+
+    let failed_swaps = block
+        .transactions
+        .iter()
+        .enumerate()
+        .filter(|(_, tx)| tx.meta.as_ref().is_some_and(|meta| meta.err.is_some()))
+        .flat_map(|(tx_index, tx)| {
+            parse_failed_transaction(tx, slot, timestamp_ms, tx_index as u32)
+        })
+        .collect();
+
+    FailedSwaps { failed_swaps }
+}
+
+// ============================================================================
+// 流动性 Map Handler: 输出成功交易中 DAMM V1 的添加 / 移除流动性
+// ============================================================================
+#[substreams::handlers::map]
+fn meteora_liquidity(block: Block) -> LiquidityEvents {
+    let slot = block.slot;
+    let timestamp_ms = block
+        .block_time
+        .as_ref()
+        .map(|t| t.timestamp as u64 * 1000)
+        .unwrap_or(0);
+
+    let events = block
+        .transactions
+        .iter()
+        .enumerate()
+        .filter(|(_, tx)| tx.meta.as_ref().is_some_and(|meta| meta.err.is_none()))
+        .flat_map(|(tx_index, tx)| parse_liquidity_events(tx, slot, timestamp_ms, tx_index as u32))
+        .collect();
+
+    LiquidityEvents { events }
+}
+
+// ============================================================================
+// DLMM 池子事件 Map Handler: 输出成功交易中 DLMM 的 Swap / 参数更新 / GoToABin 事件
+// ============================================================================
+#[substreams::handlers::map]
+fn meteora_dlmm_events(block: Block) -> DlmmPairEvents {
+    let slot = block.slot;
+    let timestamp_ms = block
+        .block_time
+        .as_ref()
+        .map(|t| t.timestamp as u64 * 1000)
+        .unwrap_or(0);
+
+    let events = block
+        .transactions
+        .iter()
+        .enumerate()
+        .filter(|(_, tx)| tx.meta.as_ref().is_some_and(|meta| meta.err.is_none()))
+        .flat_map(|(tx_index, tx)| parse_pair_events(tx, slot, timestamp_ms, tx_index as u32))
+        .collect();
+
+    DlmmPairEvents { events }
+}
+
+// ============================================================================
+// DLMM 池子 Store Handler: 按 `<lb_pair>:<field>` 记录 active bin、费率参数与最近 Swap 时间
+// ============================================================================
+#[substreams::handlers::store]
+fn store_meteora_dlmm_pairs(events: DlmmPairEvents, store: StoreSetInt64) {
+    for event in events.events.iter() {
+        store_pair_event(&store, event);
+    }
+}
+
+// ============================================================================
+// DLMM 池子状态 Map Handler: active bin 移动时输出池子状态 (bin 穿越历史)
+// ============================================================================
+#[substreams::handlers::map]
+fn meteora_dlmm_pair_states(events: DlmmPairEvents, pairs: StoreGetInt64) -> DlmmPairStates {
+    DlmmPairStates {
+        states: pair_states(&events.events, &pairs),
+    }
+}
+
+// ============================================================================
+// 手续费 Store Handlers: 按池子 / 平台 / UTC 日期累加各项手续费
+// key: `<维度>:<fee_mint>:<field>`，维度分别为 pool / platform / <YYYY-MM-DD>:<platform>
+// ============================================================================
+#[substreams::handlers::store]
+fn store_meteora_pool_fees(events: SwapEvents, store: StoreAddInt64) {
+    for event in events.swaps.iter() {
+        add_fees(&store, event, FeeScope::ScopePool);
+    }
+}
+
+#[substreams::handlers::store]
+fn store_meteora_platform_fees(events: SwapEvents, store: StoreAddInt64) {
+    for event in events.swaps.iter() {
+        add_fees(&store, event, FeeScope::ScopePlatform);
+    }
+}
+
+#[substreams::handlers::store]
+fn store_meteora_daily_fees(events: SwapEvents, store: StoreAddInt64) {
+    for event in events.swaps.iter() {
+        add_fees(&store, event, FeeScope::ScopeDay);
+    }
+}
+
+// ============================================================================
+// 手续费快照 Map Handler: 输出本区块各维度的手续费增量与累计值
+// ============================================================================
+#[substreams::handlers::map]
+fn meteora_fee_snapshots(
+    pool_fees: Deltas<DeltaInt64>,
+    platform_fees: Deltas<DeltaInt64>,
+    daily_fees: Deltas<DeltaInt64>,
+) -> FeeSnapshots {
+    let mut snapshots = fee_snapshots(&pool_fees, FeeScope::ScopePool);
+    snapshots.extend(fee_snapshots(&platform_fees, FeeScope::ScopePlatform));
+    snapshots.extend(fee_snapshots(&daily_fees, FeeScope::ScopeDay));
+
+    FeeSnapshots { snapshots }
+}
+
+/// 解析区块内所有成功交易的 Swap 事件，只保留 `keep` 返回 true 的事件
+fn collect_swap_events(
+    params: &Params,
+    block: &Block,
+    keep: impl Fn(&SwapEvent) -> bool,
+) -> SwapEvents {
     let mut swap_events = SwapEvents::default();
     let slot = block.slot;
     let timestamp_ms = block
@@ -37,8 +262,10 @@ fn meteora(block: Block) -> SwapEvents {
         }
 
         // 解析交易中的 Swap 事件
-        if let Some(events) = parse_transaction(tx, slot, timestamp_ms, tx_index as u32) {
-            swap_events.swaps.extend(events);
+        if let Some(events) = parse_transaction(tx, params, slot, timestamp_ms, tx_index as u32) {
+            swap_events
+                .swaps
+                .extend(events.into_iter().filter(|event| keep(event)));
         }
     }
 
@@ -50,6 +277,7 @@ fn meteora(block: Block) -> SwapEvents {
 // ============================================================================
 fn parse_transaction(
     tx: &ConfirmedTransaction,
+    params: &Params,
     slot: u64,
     timestamp_ms: u64,
     tx_index: u32,
@@ -58,22 +286,24 @@ fn parse_transaction(
     let message = transaction.message.as_ref()?;
     let meta = tx.meta.as_ref()?;
 
-    // 获取交易签名
-    let signature = bs58::encode(&transaction.signatures[0]).into_string();
-
-    // 获取发起者 (第一个签名者)
-    let signer = get_account_key(message, meta, 0)?;
-    let signer_str = bs58::encode(&signer).into_string();
+    // 完整账户列表的借用视图 (包含 ALT 补充地址)，每笔交易只构建一次
+    let account_keys = AccountKeys::new(message, meta);
 
-    // 获取完整账户列表 (包含 ALT 补充地址)
-    let account_keys = resolved_account_keys(message, Some(meta));
+    // 交易签名与发起者 (第一个签名者)
+    // Base58 编码延迟到命中 Swap 之后，绝大多数无关交易不产生编码开销
+    let signature_bytes = transaction.signatures.first()?;
+    let signer = account_keys.get(0)?;
+    let mut signer_encoded: Option<String> = None;
+    let mut signature_encoded: Option<String> = None;
 
+    let instructions = flatten_instructions(message, meta);
+    let mut temporary_wsol: Option<Vec<TemporaryWsol>> = None;
+    let mut cost: Option<TransactionCost> = None;
     let mut swap_events = Vec::new();
-    let mut seen_pools: std::collections::HashSet<String> = std::collections::HashSet::new();
 
     // 遍历所有指令 (包括内部指令)
-    for inst in get_flattened_instructions(tx) {
-        let program_id_index = inst.program_id_index() as usize;
+    for (position, inst) in instructions.iter().enumerate() {
+        let program_id_index = inst.program_id_index as usize;
 
         // 获取程序 ID，如果索引越界则跳过
         let program_id = match account_keys.get(program_id_index) {
@@ -87,83 +317,172 @@ fn parse_transaction(
             None => continue,
         };
 
-        // 获取指令数据
-        let data = inst.data();
-        if data.len() < 8 {
-            continue;
-        }
-
-        // 匹配 Swap Discriminator
-        let discriminator: [u8; 8] = match data[0..8].try_into() {
-            Ok(d) => d,
-            Err(_) => continue,
+        // 按程序 ID 用 IDL 生成的解码器解码，只处理 Swap 指令 (同时得到交易意图参数)
+        let args = match decode_swap_args(program_id, inst.data) {
+            Some(args) => args,
+            None => continue,
         };
-        if !is_swap_discriminator(&discriminator, &platform) {
-            continue;
-        }
 
         // 获取指令的 accounts 列表
-        let inst_accounts = inst.accounts();
+        let inst_accounts = inst.accounts;
 
-        // 提取 Pool 地址
-        let pool_index = get_pool_account_index(&platform);
+        // 提取 Pool 地址 (保持原始字节，输出时再编码)
         let pool = inst_accounts
-            .get(pool_index)
+            .get(get_pool_account_index(platform))
             .and_then(|&idx| account_keys.get(idx as usize))
-            .map(|k| bs58::encode(k).into_string())
             .unwrap_or_default();
 
-        // 获取用户 Token 账户的 account_index (用于在 TokenBalance 中查找)
-        let (input_acc_idx, output_acc_idx) = get_user_token_account_indices(&platform);
+        // 真实交易者: 用户 Token 账户的 owner (Squads 金库、Bot PDA、代付手续费的 relayer 场景下不等于签名者)
+        let signer_str = signer_encoded.get_or_insert_with(|| bs58::encode(signer).into_string());
+        let trader = find_trader(meta, inst_accounts, platform).unwrap_or(signer_str.as_str());
 
-        let input_account_idx = inst_accounts.get(input_acc_idx).map(|&idx| idx as u32);
-        let output_account_idx = inst_accounts.get(output_acc_idx).map(|&idx| idx as u32);
+        // 临时 WSOL 账户每笔交易只识别一次
+        let temporary_wsol = temporary_wsol.get_or_insert_with(|| {
+            find_temporary_wsol_accounts(meta, &account_keys, &instructions)
+        });
+        let children = get_child_instructions(&instructions, position);
+
+        // 从 Token Balance 变化计算 Swap 金额，失败时输出占位事件 (金额为 0) 并记录原因
+        let (amounts, parse_status, parse_reason) = match resolve_swap_amounts(
+            meta,
+            &account_keys,
+            &instructions,
+            position,
+            temporary_wsol,
+            trader,
+            platform,
+        ) {
+            Ok(amounts) => (amounts, ParseStatus::StatusOk, String::new()),
+            Err((status, reason)) => (SwapAmounts::default(), status, reason),
+        };
 
-        // 检查是否已处理过这个 pool (避免 CPI 造成的重复)
-        if seen_pools.contains(&pool) {
-            continue;
-        }
-        seen_pools.insert(pool.clone());
+        // 交易意图: 与实际成交金额比较滑点余量
+        let (input_amount, output_amount) = match amounts.side {
+            SwapSide::SideBuy => (amounts.quote_amount, amounts.base_amount),
+            SwapSide::SideSell => (amounts.base_amount, amounts.quote_amount),
+            SwapSide::SideUnknown => (0, 0),
+        };
+        let slippage_headroom = slippage_headroom_bps(&args, input_amount, output_amount);
+
+        // 交易后的池子储备与价格
+        let pool_state = pool_state(
+            meta,
+            &account_keys,
+            children,
+            inst_accounts,
+            platform,
+            &amounts,
+        );
+
+        // 手续费拆分: 按 Swap 方向把收取手续费的输入 / 输出 token 换算为 base / quote
+        let fees = swap_fees(
+            meta,
+            &account_keys,
+            &instructions,
+            position,
+            children,
+            platform,
+        );
+        let fee_in_quote = fees.as_ref().is_some_and(|fees| match amounts.side {
+            SwapSide::SideBuy => fees.on_input,
+            SwapSide::SideSell => !fees.on_input,
+            SwapSide::SideUnknown => false,
+        });
 
-        // 从 Token Balance 变化计算 Swap 金额
-        let (base_mint, quote_mint, base_amount, quote_amount, base_decimals, quote_decimals, side) =
-            match extract_swap_amounts_by_accounts(
+        // 路由归因: 所在外层指令的程序与 CPI 深度
+        let outer_program_id = message
+            .instructions
+            .get(inst.instruction_index as usize)
+            .and_then(|outer| account_keys.get(outer.program_id_index as usize))
+            .unwrap_or_default();
+        let route_source = classify_route(inst.inner_instruction_index, outer_program_id);
+
+        // 执行成本每笔交易只解析一次
+        let cost = cost.get_or_insert_with(|| {
+            transaction_cost(
                 meta,
-                &signer_str,
-                input_account_idx,
-                output_account_idx,
-            ) {
-                Some((bm, qm, ba, qa, bd, qd, s)) if bm != qm => (bm, qm, ba, qa, bd, qd, s),
-                _ => {
-                    // 找不到金额或 mint 相同时，输出占位事件 (金额为 0)
-                    (
-                        String::new(),
-                        String::new(),
-                        0,
-                        0,
-                        0,
-                        0,
-                        SwapSide::SideUnknown,
-                    )
-                }
-            };
+                &account_keys,
+                &instructions,
+                transaction.signatures.len(),
+            )
+        });
 
-        swap_events.push(SwapEvent {
-            pool,
-            signature: signature.clone(),
-            user: signer_str.clone(),
+        let mut event = SwapEvent {
             platform: platform.to_string(),
             timestamp_ms,
             slot,
             tx_index,
-            base_mint,
-            quote_mint,
-            base_amount,
-            quote_amount,
-            side: side.into(),
-            base_decimals,
-            quote_decimals,
-        });
+            base_amount: amounts.base_amount,
+            quote_amount: amounts.quote_amount,
+            side: amounts.side.into(),
+            base_decimals: amounts.base_decimals,
+            quote_decimals: amounts.quote_decimals,
+            instruction_index: inst.instruction_index,
+            inner_instruction_index: inst.inner_instruction_index,
+            parse_status: parse_status.into(),
+            parse_reason,
+            base_token_2022: amounts.base_transfer.token_2022,
+            quote_token_2022: amounts.quote_transfer.token_2022,
+            base_gross_amount: amounts.base_transfer.gross_amount,
+            base_net_amount: amounts.base_transfer.net_amount,
+            base_transfer_fee: amounts.base_transfer.transfer_fee,
+            quote_gross_amount: amounts.quote_transfer.gross_amount,
+            quote_net_amount: amounts.quote_transfer.net_amount,
+            quote_transfer_fee: amounts.quote_transfer.transfer_fee,
+            transfer_hook: amounts.base_transfer.transfer_hook
+                || amounts.quote_transfer.transfer_hook,
+            base_fee_inferred: amounts.base_transfer.fee_inferred,
+            quote_fee_inferred: amounts.quote_transfer.fee_inferred,
+            route_source: route_source.into(),
+            cpi_depth: cpi_depth(inst),
+            compute_unit_limit: cost.compute_unit_limit,
+            compute_unit_price: cost.compute_unit_price,
+            compute_units_consumed: cost.compute_units_consumed,
+            fee: cost.fee,
+            base_fee: cost.base_fee,
+            priority_fee: cost.priority_fee,
+            jito_tip: cost.jito_tip,
+            intent: args.intent.into(),
+            requested_amount: args.requested_amount,
+            slippage_bound: args.slippage_bound,
+            max_price_impact_bps: args.max_price_impact_bps,
+            slippage_headroom_bps: slippage_headroom.unwrap_or(0),
+            price_before: price_before(platform, pool_state.as_ref()),
+            pool_state,
+            fees: fees.map(|fees| fees.amounts),
+            fee_in_quote,
+            ..Default::default()
+        };
+
+        // route_id 与编码参数无关，两种编码下都输出 Base58 签名拼接的字符串
+        let signature_str =
+            signature_encoded.get_or_insert_with(|| bs58::encode(signature_bytes).into_string());
+        event.route_id = format!("{}:{}", signature_str, inst.instruction_index);
+
+        match params.pubkey_encoding {
+            PubkeyEncoding::Base58 => {
+                event.pool = bs58::encode(pool).into_string();
+                event.signature = signature_str.clone();
+                event.outer_program_id = bs58::encode(outer_program_id).into_string();
+                event.user = signer_str.clone();
+                event.fee_payer = signer_str.clone();
+                event.trader = trader.to_string();
+                event.base_mint = amounts.base_mint;
+                event.quote_mint = amounts.quote_mint;
+            }
+            PubkeyEncoding::Raw => {
+                event.pool_raw = pool.to_vec();
+                event.signature_raw = signature_bytes.clone();
+                event.user_raw = signer.to_vec();
+                event.fee_payer_raw = signer.to_vec();
+                event.trader_raw = decode_pubkey(trader);
+                event.outer_program_id_raw = outer_program_id.to_vec();
+                event.base_mint_raw = decode_pubkey(&amounts.base_mint);
+                event.quote_mint_raw = decode_pubkey(&amounts.quote_mint);
+            }
+        }
+
+        swap_events.push(event);
     }
 
     if swap_events.is_empty() {
@@ -177,6 +496,21 @@ fn parse_transaction(
 // 辅助函数
 // ============================================================================
 
+/// Swap 金额 (解析失败时为占位值，金额为 0)
+#[derive(Default)]
+struct SwapAmounts {
+    base_mint: String,
+    quote_mint: String,
+    base_amount: u64,
+    quote_amount: u64,
+    base_decimals: u32,
+    quote_decimals: u32,
+    side: SwapSide,
+    /// base / quote 两条转账腿的 Token-2022 信息
+    base_transfer: TransferLeg,
+    quote_transfer: TransferLeg,
+}
+
 /// 匹配程序 ID，返回平台名称
 fn match_program(program_id: &[u8]) -> Option<&'static str> {
     for (target_id, platform_name) in FILTER_PROGRAM_IDS.iter() {
@@ -187,29 +521,35 @@ fn match_program(program_id: &[u8]) -> Option<&'static str> {
     None
 }
 
-/// 检查是否是 Swap 类型的 Discriminator
-fn is_swap_discriminator(discriminator: &[u8; 8], platform: &str) -> bool {
-    match platform {
-        "meteora_dlmm" => {
-            discriminator == &DLMM_SWAP
-                || discriminator == &DLMM_SWAP2
-                || discriminator == &DLMM_SWAP_EXACT_OUT
-                || discriminator == &DLMM_SWAP_EXACT_OUT2
-                || discriminator == &DLMM_SWAP_WITH_PRICE_IMPACT
-                || discriminator == &DLMM_SWAP_WITH_PRICE_IMPACT2
-        }
-        "meteora_damm" => discriminator == &DAMM_SWAP || discriminator == &DAMM_SWAP2,
-        "meteora_dbc" => discriminator == &DBC_SWAP || discriminator == &DBC_SWAP2,
-        _ => false,
+/// 根据外层程序分类路由来源: 外层指令本身即 Swap 为直接调用，否则按外层程序查表
+fn classify_route(inner_instruction_index: u32, outer_program_id: &[u8]) -> RouteSource {
+    if inner_instruction_index == 0 {
+        return RouteSource::RouteDirect;
+    }
+
+    ROUTE_PROGRAM_IDS
+        .iter()
+        .find(|(id, _)| id.0.as_slice() == outer_program_id)
+        .map(|(_, source)| *source)
+        .unwrap_or(RouteSource::RouteUnknown)
+}
+
+/// CPI 深度 (stack_height - 1)，旧区块缺少 stack_height 时内层指令按 1 计
+fn cpi_depth(inst: &FlattenedInstruction) -> u32 {
+    match inst.stack_height {
+        Some(height) => height.saturating_sub(1),
+        None if inst.inner_instruction_index == 0 => 0,
+        None => 1,
     }
 }
 
 /// 获取 Pool 地址在 accounts 中的索引 (根据 IDL)
 fn get_pool_account_index(platform: &str) -> usize {
     match platform {
-        "meteora_dlmm" => 0, // lb_pair 在 accounts[0]
-        "meteora_damm" => 1, // pool 在 accounts[1], accounts[0] 是 pool_authority
+        "meteora_dlmm" => 0,    // lb_pair 在 accounts[0]
+        "meteora_damm" => 1,    // pool 在 accounts[1], accounts[0] 是 pool_authority
         "meteora_dbc" => 2, // pool 在 accounts[2], accounts[0] 是 pool_authority, accounts[1] 是 config
+        "meteora_damm_v1" => 0, // pool 在 accounts[0]
         _ => 0,
     }
 }
@@ -224,74 +564,327 @@ fn get_user_token_account_indices(platform: &str) -> (usize, usize) {
         "meteora_damm" => (2, 3),
         // DBC: input_token_account (3), output_token_account (4)
         "meteora_dbc" => (3, 4),
+        // DAMM V1: user_source_token (1), user_destination_token (2)
+        "meteora_damm_v1" => (1, 2),
         _ => (0, 0),
     }
 }
 
+/// 从 Token Balance 中查找 Swap 用户 Token 账户的 owner (真实交易者)
+/// 输入账户优先；临时 WSOL 账户没有余额记录，此时取输出账户的 owner
+fn find_trader<'a>(
+    meta: &'a TransactionStatusMeta,
+    inst_accounts: &[u8],
+    platform: &str,
+) -> Option<&'a str> {
+    let (input_position, output_position) = get_user_token_account_indices(platform);
+
+    [input_position, output_position]
+        .iter()
+        .filter_map(|&position| inst_accounts.get(position))
+        .find_map(|&index| account_owner(meta, index as u32))
+}
+
+/// 校验指令账户后提取 Swap 金额，失败时返回解析状态与原因
+fn resolve_swap_amounts(
+    meta: &TransactionStatusMeta,
+    account_keys: &AccountKeys,
+    instructions: &[FlattenedInstruction],
+    position: usize,
+    temporary_wsol: &[TemporaryWsol],
+    trader: &str,
+    platform: &str,
+) -> Result<SwapAmounts, (ParseStatus, String)> {
+    let inst_accounts = instructions[position].accounts;
+    let children = get_child_instructions(instructions, position);
+    let (input_position, output_position) = get_user_token_account_indices(platform);
+    let mut resolved = [0u32; 2];
+
+    for (resolved_index, position) in resolved.iter_mut().zip([input_position, output_position]) {
+        let index = match inst_accounts.get(position) {
+            Some(&index) => index as usize,
+            None => {
+                return Err((
+                    ParseStatus::StatusAccountIndexOutOfRange,
+                    format!(
+                        "instruction has {} accounts, user token account expected at #{position}",
+                        inst_accounts.len()
+                    ),
+                ))
+            }
+        };
+
+        if index >= account_keys.len() {
+            // 越界的索引只可能指向 ALT 地址: 区块数据缺少加载结果时单独归类
+            let status = if account_keys.lookups_unresolved() {
+                ParseStatus::StatusAltMissing
+            } else {
+                ParseStatus::StatusAccountIndexOutOfRange
+            };
+            return Err((
+                status,
+                format!(
+                    "account index {index} out of range, transaction has {} keys",
+                    account_keys.len()
+                ),
+            ));
+        }
+
+        *resolved_index = index as u32;
+    }
+
+    // DAMM V1 的资金经由 Dynamic Vault 存取，优先按进出 vault 的转账计算
+    let vault_amounts = match platform {
+        "meteora_damm_v1" => damm_v1::swap_amounts_from_vaults(
+            meta,
+            account_keys,
+            children,
+            inst_accounts,
+            resolved[0],
+            resolved[1],
+        )?,
+        _ => None,
+    };
+    // 其余平台按 Swap 自身子指令中进出用户账户的转账计算，多跳经过同一账户时不受其它跳影响
+    // 缺少内层指令时退化为交易级的余额差
+    let transfer_amounts = match vault_amounts {
+        Some(amounts) => Some(amounts),
+        None => extract_swap_amounts_by_transfers(
+            meta,
+            account_keys,
+            children,
+            resolved[0],
+            resolved[1],
+        )?,
+    };
+    let mut amounts = match transfer_amounts {
+        Some(amounts) => amounts,
+        None => extract_swap_amounts_by_accounts(
+            meta,
+            account_keys,
+            children,
+            temporary_wsol,
+            trader,
+            resolved[0],
+            resolved[1],
+        )?,
+    };
+
+    if amounts.base_mint == amounts.quote_mint {
+        return Err((
+            ParseStatus::StatusSameMint,
+            format!("input and output mint are both {}", amounts.base_mint),
+        ));
+    }
+
+    // 输入腿为用户转出，输出腿为用户收到；卖出时 base 是用户付出的一方
+    let is_sell = amounts.side == SwapSide::SideSell;
+    let (input_amount, output_amount) = if is_sell {
+        (amounts.base_amount, amounts.quote_amount)
+    } else {
+        (amounts.quote_amount, amounts.base_amount)
+    };
+    let input_leg = find_transfer_leg(
+        meta,
+        account_keys,
+        instructions,
+        children,
+        resolved[0],
+        true,
+        input_amount,
+    );
+    let output_leg = find_transfer_leg(
+        meta,
+        account_keys,
+        instructions,
+        children,
+        resolved[1],
+        false,
+        output_amount,
+    );
+    (amounts.base_transfer, amounts.quote_transfer) = if is_sell {
+        (input_leg, output_leg)
+    } else {
+        (output_leg, input_leg)
+    };
+
+    Ok(amounts)
+}
+
+/// 根据 Swap 子指令 (CPI) 中的 Token 转账提取金额
+/// 输入 = 用户输入账户转出的总额；输出 = 转入用户输出账户的总额 (扣除 TransferCheckedWithFee 声明的手续费)
+/// mint 与精度取自用户账户的余额记录，临时 WSOL 账户没有记录时取对手方 (池子 vault) 的记录
+/// 任一方向找不到转账或无法确定 mint 时返回 Ok(None)，由调用方退化为余额差计算
+fn extract_swap_amounts_by_transfers(
+    meta: &TransactionStatusMeta,
+    account_keys: &AccountKeys,
+    children: &[FlattenedInstruction],
+    input_account_idx: u32,
+    output_account_idx: u32,
+) -> Result<Option<SwapAmounts>, (ParseStatus, String)> {
+    let mut input_amount: i128 = 0;
+    let mut output_amount: i128 = 0;
+    let mut input_counterparty = None;
+    let mut output_counterparty = None;
+
+    for child in children {
+        let transfer = match account_keys
+            .get(child.program_id_index as usize)
+            .and_then(|id| parse_token_transfer(id, child.accounts, child.data))
+        {
+            Some(t) => t,
+            None => continue,
+        };
+
+        if transfer.source == input_account_idx {
+            input_amount += transfer.amount as i128;
+            input_counterparty.get_or_insert(transfer.destination);
+        } else if transfer.destination == output_account_idx {
+            let fee = transfer.fee.unwrap_or(0).min(transfer.amount);
+            output_amount += (transfer.amount - fee) as i128;
+            output_counterparty.get_or_insert(transfer.source);
+        }
+    }
+
+    let mint = |account: u32, counterparty: u32| {
+        account_mint(meta, account).or_else(|| account_mint(meta, counterparty))
+    };
+    let (input, output) = match (
+        input_counterparty.and_then(|c| mint(input_account_idx, c)),
+        output_counterparty.and_then(|c| mint(output_account_idx, c)),
+    ) {
+        (Some(input), Some(output)) => (input, output),
+        _ => return Ok(None),
+    };
+    let ((input_mint, input_decimals), (output_mint, output_decimals)) = (input, output);
+    let input_amount = checked_amount(input_amount, &input_mint)?;
+    let output_amount = checked_amount(output_amount, &output_mint)?;
+
+    // 与余额差计算一致: 收到 SOL 为卖出，其余按买入 (收到的一方为 base)
+    let amounts = if output_mint == WSOL_MINT {
+        SwapAmounts {
+            base_mint: input_mint,
+            quote_mint: output_mint,
+            base_amount: input_amount,
+            quote_amount: output_amount,
+            base_decimals: input_decimals,
+            quote_decimals: output_decimals,
+            side: SwapSide::SideSell,
+            ..Default::default()
+        }
+    } else {
+        SwapAmounts {
+            base_mint: output_mint,
+            quote_mint: input_mint,
+            base_amount: output_amount,
+            quote_amount: input_amount,
+            base_decimals: output_decimals,
+            quote_decimals: input_decimals,
+            side: SwapSide::SideBuy,
+            ..Default::default()
+        }
+    };
+
+    Ok(Some(amounts))
+}
+
 /// 根据指令的输入/输出账户索引，从 Token Balance 变化中提取 Swap 金额
-/// 同时处理临时 WSOL 账户的情况（通过 Native SOL 余额变化）
-/// signer: 交易签名者地址，只统计 owner == signer 的 Token 变化
+/// 同时处理临时 WSOL 账户的情况（交易内创建并关闭，SOL 金额取自该账户的资金流）
+/// trader: 真实交易者地址，兜底时只统计 owner == trader 的 Token 变化
+/// children: Swap 指令的子指令 (CPI)，用于读取临时 WSOL 账户的精确转账金额
+/// 余额变化以 i128 计算，金额超出 u64 时返回 STATUS_AMOUNT_OVERFLOW
 fn extract_swap_amounts_by_accounts(
     meta: &TransactionStatusMeta,
-    signer: &str,
-    input_account_idx: Option<u32>,
-    output_account_idx: Option<u32>,
-) -> Option<(String, String, u64, u64, u32, u32, SwapSide)> {
+    account_keys: &AccountKeys,
+    children: &[FlattenedInstruction],
+    temporary_wsol: &[TemporaryWsol],
+    trader: &str,
+    input_account_idx: u32,
+    output_account_idx: u32,
+) -> Result<SwapAmounts, (ParseStatus, String)> {
     let pre_balances = &meta.pre_token_balances;
     let post_balances = &meta.post_token_balances;
 
-    // WSOL mint 地址
-    const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
-
     // 首先尝试通过指定的账户索引查找
-    if let (Some(input_idx), Some(output_idx)) = (input_account_idx, output_account_idx) {
-        let input_change = find_token_balance_change(pre_balances, post_balances, input_idx);
-        let output_change = find_token_balance_change(pre_balances, post_balances, output_idx);
-
-        if let (
-            Some((input_mint, input_amount, input_decimals)),
-            Some((output_mint, output_amount, output_decimals)),
-        ) = (input_change, output_change)
-        {
-            // 输入应该是负数 (用户付出)，输出应该是正数 (用户收到)
-            if input_amount < 0 && output_amount > 0 {
-                return Some((
-                    output_mint,            // base_mint (收到的)
-                    input_mint,             // quote_mint (付出的)
-                    output_amount as u64,   // base_amount
-                    (-input_amount) as u64, // quote_amount
-                    output_decimals,        // base_decimals
-                    input_decimals,         // quote_decimals
-                    SwapSide::SideBuy,      // 用 quote 买 base
-                ));
-            }
+    let input_change = find_token_balance_change(pre_balances, post_balances, input_account_idx);
+    let output_change = find_token_balance_change(pre_balances, post_balances, output_account_idx);
+
+    if let (
+        Some((input_mint, input_amount, input_decimals)),
+        Some((output_mint, output_amount, output_decimals)),
+    ) = (&input_change, &output_change)
+    {
+        // 输入应该是负数 (用户付出)，输出应该是正数 (用户收到)
+        if *input_amount < 0 && *output_amount > 0 {
+            return Ok(SwapAmounts {
+                base_mint: output_mint.clone(), // 收到的
+                quote_mint: input_mint.clone(), // 付出的
+                base_amount: checked_amount(*output_amount, output_mint)?,
+                quote_amount: checked_amount(*input_amount, input_mint)?,
+                base_decimals: *output_decimals,
+                quote_decimals: *input_decimals,
+                side: SwapSide::SideBuy, // 用 quote 买 base
+                ..Default::default()
+            });
+        }
+    }
+
+    // 临时 WSOL 账户: Token Balance 中没有记录，优先取 Swap 内部从该账户转出/转入的金额
+    let find_wsol = |index: u32| temporary_wsol.iter().find(|a| a.account_index == index);
+
+    // 场景 A: 用户付出 SOL，收到 Token (Buy)
+    if let (Some(wsol), Some((base_mint, base_change, base_decimals))) =
+        (find_wsol(input_account_idx), &output_change)
+    {
+        // 缺少内层指令时退化为账户注资扣除免租押金
+        let sol_amount = swap_transfer_amount(account_keys, children, wsol.account_index, true)
+            .unwrap_or_else(|| wsol.wrapped_amount());
+        if *base_change > 0 && sol_amount > 0 {
+            return Ok(SwapAmounts {
+                base_mint: base_mint.clone(),
+                quote_mint: WSOL_MINT.to_string(),
+                base_amount: checked_amount(*base_change, base_mint)?,
+                quote_amount: sol_amount,
+                base_decimals: *base_decimals,
+                quote_decimals: SOL_DECIMALS,
+                side: SwapSide::SideBuy,
+                ..Default::default()
+            });
         }
     }
 
-    // 收集签名者拥有的 Token Balance 变化 (只统计 owner == signer)
-    let mut changes: Vec<(String, i64, u32)> = Vec::new();
+    // 场景 B: 用户付出 Token，收到 SOL (Sell)
+    // 收到的 SOL 随关闭账户一并退回，只能从 Swap 内部转入该账户的金额得到
+    if let (Some(wsol), Some((base_mint, base_change, base_decimals))) =
+        (find_wsol(output_account_idx), &input_change)
+    {
+        let sol_amount = swap_transfer_amount(account_keys, children, wsol.account_index, false);
+        if let Some(sol_amount) = sol_amount.filter(|_| *base_change < 0) {
+            return Ok(SwapAmounts {
+                base_mint: base_mint.clone(),
+                quote_mint: WSOL_MINT.to_string(),
+                base_amount: checked_amount(*base_change, base_mint)?,
+                quote_amount: sol_amount,
+                base_decimals: *base_decimals,
+                quote_decimals: SOL_DECIMALS,
+                side: SwapSide::SideSell,
+                ..Default::default()
+            });
+        }
+    }
+
+    // 收集交易者拥有的 Token Balance 变化 (只统计 owner == trader)
+    let mut changes: Vec<(String, i128, u32)> = Vec::new();
 
     for post in post_balances.iter() {
-        // 只统计签名者的 Token 变化
-        if post.owner != signer {
+        // 只统计交易者的 Token 变化
+        if post.owner != trader {
             continue;
         }
 
         let account_idx = post.account_index;
-        let pre_amount: u64 = pre_balances
-            .iter()
-            .find(|b| b.account_index == account_idx)
-            .and_then(|b| b.ui_token_amount.as_ref())
-            .and_then(|amt| amt.amount.parse().ok())
-            .unwrap_or(0);
-
-        let post_amount: u64 = post
-            .ui_token_amount
-            .as_ref()
-            .and_then(|amt| amt.amount.parse().ok())
-            .unwrap_or(0);
-
-        let change = post_amount as i64 - pre_amount as i64;
+        let pre = pre_balances.iter().find(|b| b.account_index == account_idx);
+        let change = token_amount(Some(post)) - token_amount(pre);
         if change != 0 {
             let mint = post.mint.clone();
             let decimals = post
@@ -303,7 +896,7 @@ fn extract_swap_amounts_by_accounts(
         }
     }
 
-    // 方案 1: 找到一个负变化和一个正变化（两个不同 Token）
+    // 兜底: 找到交易者的一个负变化和一个正变化（两个不同 Token）
     let negative = changes.iter().find(|(_, c, _)| *c < 0);
     let positive = changes.iter().find(|(_, c, _)| *c > 0);
 
@@ -313,86 +906,49 @@ fn extract_swap_amounts_by_accounts(
     ) = (negative, positive)
     {
         if quote_mint != base_mint {
-            return Some((
-                base_mint.clone(),
-                quote_mint.clone(),
-                *base_change as u64,
-                (-*quote_change) as u64,
-                *base_decimals,
-                *quote_decimals,
-                SwapSide::SideBuy,
-            ));
+            return Ok(SwapAmounts {
+                base_mint: base_mint.clone(),
+                quote_mint: quote_mint.clone(),
+                base_amount: checked_amount(*base_change, base_mint)?,
+                quote_amount: checked_amount(*quote_change, quote_mint)?,
+                base_decimals: *base_decimals,
+                quote_decimals: *quote_decimals,
+                side: SwapSide::SideBuy,
+                ..Default::default()
+            });
         }
     }
 
-    // 方案 2: 只有一个 Token 变化 + Native SOL 变化（临时 WSOL 场景）
-    // 计算用户的 Native SOL 变化 (preBalances[0] - postBalances[0] - fee)
-    if !meta.pre_balances.is_empty() && !meta.post_balances.is_empty() {
-        let pre_sol = meta.pre_balances[0] as i64;
-        let post_sol = meta.post_balances[0] as i64;
-        let fee = meta.fee as i64;
-
-        // 用户实际的 SOL 变化（排除 gas 费用）
-        let sol_change = post_sol - pre_sol + fee; // 负数 = 付出，正数 = 收到
-
-        // 如果有显著的 SOL 变化（超过 1000 lamports 以排除噪音）
-        if sol_change.abs() > 1000 {
-            // 场景 A: 用户付出 SOL，收到 Token (Buy)
-            if sol_change < 0 && positive.is_some() && negative.is_none() {
-                let (base_mint, base_change, base_decimals) = positive.unwrap();
-                return Some((
-                    base_mint.clone(),
-                    WSOL_MINT.to_string(),
-                    *base_change as u64,
-                    (-sol_change) as u64,
-                    *base_decimals,
-                    9, // SOL decimals
-                    SwapSide::SideBuy,
-                ));
-            }
-
-            // 场景 B: 用户付出 Token，收到 SOL (Sell)
-            if sol_change > 0 && negative.is_some() && positive.is_none() {
-                let (quote_mint, quote_change, quote_decimals) = negative.unwrap();
-                return Some((
-                    WSOL_MINT.to_string(),
-                    quote_mint.clone(),
-                    sol_change as u64,
-                    (-*quote_change) as u64,
-                    9, // SOL decimals
-                    *quote_decimals,
-                    SwapSide::SideSell,
-                ));
-            }
-        }
-    }
+    Err((
+        ParseStatus::StatusNoBalanceChange,
+        format!("no usable balance change for accounts {input_account_idx} / {output_account_idx}"),
+    ))
+}
 
-    None
+/// 余额变化的绝对值转为 u64 金额，超出 u64 时返回溢出诊断而不是截断
+fn checked_amount(change: i128, mint: &str) -> Result<u64, (ParseStatus, String)> {
+    u64::try_from(change.unsigned_abs()).map_err(|_| {
+        (
+            ParseStatus::StatusAmountOverflow,
+            format!("balance change {change} of mint {mint} exceeds u64"),
+        )
+    })
 }
 
 /// 查找特定账户的 Token Balance 变化
 /// 返回: (mint, change, decimals)
 fn find_token_balance_change(
-    pre_balances: &[substreams_solana::pb::sf::solana::r#type::v1::TokenBalance],
-    post_balances: &[substreams_solana::pb::sf::solana::r#type::v1::TokenBalance],
+    pre_balances: &[TokenBalance],
+    post_balances: &[TokenBalance],
     account_index: u32,
-) -> Option<(String, i64, u32)> {
+) -> Option<(String, i128, u32)> {
     // 在 post_balances 中查找
     let post = post_balances
         .iter()
         .find(|b| b.account_index == account_index);
-
-    let post_amount: u64 = post
-        .and_then(|b| b.ui_token_amount.as_ref())
-        .and_then(|amt| amt.amount.parse().ok())
-        .unwrap_or(0);
-
-    let pre_amount: u64 = pre_balances
+    let pre = pre_balances
         .iter()
-        .find(|b| b.account_index == account_index)
-        .and_then(|b| b.ui_token_amount.as_ref())
-        .and_then(|amt| amt.amount.parse().ok())
-        .unwrap_or(0);
+        .find(|b| b.account_index == account_index);
 
     let mint = post.map(|b| b.mint.clone()).unwrap_or_default();
     let decimals = post
@@ -400,7 +956,8 @@ fn find_token_balance_change(
         .map(|a| a.decimals)
         .unwrap_or(0);
 
-    let change = post_amount as i64 - pre_amount as i64;
+    // 两个非负的 i128 数量相减不会溢出
+    let change = token_amount(post) - token_amount(pre);
 
     if change == 0 && mint.is_empty() {
         return None;
@@ -409,28 +966,7 @@ fn find_token_balance_change(
     Some((mint, change, decimals))
 }
 
-/// 获取账户地址 (支持 ALT)
-fn get_account_key(
-    message: &Message,
-    meta: &TransactionStatusMeta,
-    index: usize,
-) -> Option<Vec<u8>> {
-    let keys = resolved_account_keys(message, Some(meta));
-    keys.get(index).cloned()
-}
-
-/// 合并 message 中的 account_keys 与 ALT 补充地址
-fn resolved_account_keys(message: &Message, meta: Option<&TransactionStatusMeta>) -> Vec<Vec<u8>> {
-    let mut keys = message.account_keys.clone();
-
-    if let Some(meta) = meta {
-        if !meta.loaded_writable_addresses.is_empty() {
-            keys.extend(meta.loaded_writable_addresses.clone());
-        }
-        if !meta.loaded_readonly_addresses.is_empty() {
-            keys.extend(meta.loaded_readonly_addresses.clone());
-        }
-    }
-
-    keys
+/// Base58 mint 字符串转原始字节，空字符串 (占位事件) 或非法输入返回空
+fn decode_pubkey(address: &str) -> Vec<u8> {
+    bs58::decode(address).into_vec().unwrap_or_default()
 }
@@ -0,0 +1,142 @@
+/// Anchor emit! 事件写入日志的前缀 (sol_log_data)，其后为空格分隔的 Base64 数据
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+/// 日志超出上限被截断时运行时追加的行
+const LOG_TRUNCATED: &str = "Log truncated";
+
+/// 按调用顺序返回程序每次被调用 (外层或 CPI) 时直接写出的 `Program data:` 数据
+/// 第 k 项对应交易中该程序的第 k 条指令 (flatten_instructions 的执行顺序)
+/// 日志被截断或调用栈不匹配时返回 None
+pub fn program_data_by_invocation(
+    log_messages: &[String],
+    program_id: &str,
+) -> Option<Vec<Vec<Vec<u8>>>> {
+    let mut invocations: Vec<Vec<Vec<u8>>> = Vec::new();
+    // 调用栈: (程序 ID, 在 invocations 中的位置)
+    let mut stack: Vec<(&str, Option<usize>)> = Vec::new();
+
+    for line in log_messages {
+        if line == LOG_TRUNCATED {
+            return None;
+        }
+
+        if let Some(data) = line.strip_prefix(PROGRAM_DATA_PREFIX) {
+            if let Some(&(_, Some(invocation))) = stack.last() {
+                invocations[invocation].push(
+                    data.split(' ')
+                        .flat_map(|chunk| decode_base64(chunk).unwrap_or_default())
+                        .collect(),
+                );
+            }
+            continue;
+        }
+
+        // "Program <id> invoke [n]" / "Program <id> success" / "Program <id> failed: ..."
+        let rest = match line.strip_prefix("Program ") {
+            Some(rest) => rest,
+            None => continue,
+        };
+        let (id, status) = match rest.split_once(' ') {
+            Some(parts) => parts,
+            None => continue,
+        };
+        if status.starts_with("invoke [") {
+            let invocation = (id == program_id).then(|| {
+                invocations.push(Vec::new());
+                invocations.len() - 1
+            });
+            stack.push((id, invocation));
+        } else if status == "success" || status.starts_with("failed") {
+            match stack.pop() {
+                Some((invoked, _)) if invoked == id => {}
+                _ => return None,
+            }
+        }
+    }
+
+    Some(invocations)
+}
+
+/// 标准 Base64 (含 `=` 填充) 解码，遇到非法字符返回 None
+fn decode_base64(input: &str) -> Option<Vec<u8>> {
+    let mut output = Vec::with_capacity(input.len() * 3 / 4);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for byte in input.bytes().take_while(|&b| b != b'=') {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            output.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    Some(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAMM: &str = "cpamdpZCGKUy5JxQXB4dcpGPiikHawvSWAd6mEn1sGG";
+    const JUPITER: &str = "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4";
+
+    fn logs(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn decodes_base64_with_and_without_padding() {
+        assert_eq!(decode_base64("").unwrap(), b"");
+        assert_eq!(decode_base64("TQ==").unwrap(), b"M");
+        assert_eq!(decode_base64("TWE=").unwrap(), b"Ma");
+        assert_eq!(decode_base64("TWFu").unwrap(), b"Man");
+        assert_eq!(decode_base64("TWE").unwrap(), b"Ma");
+        assert_eq!(decode_base64("+/8=").unwrap(), [0xfb, 0xff]);
+        assert_eq!(decode_base64("TW-u"), None);
+    }
+
+    #[test]
+    fn groups_program_data_by_invocation() {
+        let logs = logs(&[
+            &format!("Program {JUPITER} invoke [1]"),
+            "Program data: AQI=",
+            &format!("Program {DAMM} invoke [2]"),
+            "Program log: Instruction: Swap",
+            "Program data: AQ== Ag==",
+            &format!("Program {DAMM} success"),
+            &format!("Program {DAMM} invoke [2]"),
+            &format!("Program {DAMM} success"),
+            &format!("Program {JUPITER} success"),
+            &format!("Program {DAMM} invoke [1]"),
+            "Program data: Aw==",
+            &format!("Program {DAMM} success"),
+        ]);
+
+        let invocations = program_data_by_invocation(&logs, DAMM).unwrap();
+        // 同一行空格分隔的多段 Base64 拼接为一条数据；外层程序写出的数据不归入 DAMM
+        assert_eq!(invocations, vec![vec![vec![1, 2]], vec![], vec![vec![3]]]);
+    }
+
+    #[test]
+    fn rejects_truncated_or_unbalanced_logs() {
+        let truncated = logs(&[&format!("Program {DAMM} invoke [1]"), LOG_TRUNCATED]);
+        assert_eq!(program_data_by_invocation(&truncated, DAMM), None);
+
+        let unbalanced = logs(&[
+            &format!("Program {JUPITER} invoke [1]"),
+            &format!("Program {DAMM} success"),
+        ]);
+        assert_eq!(program_data_by_invocation(&unbalanced, DAMM), None);
+    }
+}
@@ -0,0 +1,107 @@
+use dex_common::params::query_pairs;
+use substreams::errors::Error;
+
+/// Pubkey / 签名字段的输出编码
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PubkeyEncoding {
+    /// Base58 字符串 (默认)
+    Base58,
+    /// 原始字节，写入 `*_raw` 字段，由消费端按需解码
+    Raw,
+}
+
+/// 金额解析失败 (parse_status != STATUS_OK) 的事件如何输出
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum InvalidEvents {
+    /// 直接丢弃
+    Drop,
+    /// 与正常事件一起输出
+    Emit,
+    /// 只输出到 `meteora_diagnostics` 模块 (默认)，每个失败事件只有一个去向
+    Route,
+}
+
+/// `meteora` 模块参数，格式与 URL query 相同: `key=value&key2=value2`
+/// 例: `pubkey_encoding=raw&invalid_events=route`
+pub struct Params {
+    pub pubkey_encoding: PubkeyEncoding,
+    pub invalid_events: InvalidEvents,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            pubkey_encoding: PubkeyEncoding::Base58,
+            invalid_events: InvalidEvents::Route,
+        }
+    }
+}
+
+impl Params {
+    /// 解析参数字符串，空字符串使用默认值，未知的键或取值直接报错
+    pub fn parse(params: &str) -> Result<Self, Error> {
+        let mut parsed = Self::default();
+
+        for (key, value) in query_pairs(params)? {
+            match key {
+                "pubkey_encoding" => {
+                    parsed.pubkey_encoding = match value {
+                        "base58" => PubkeyEncoding::Base58,
+                        "raw" => PubkeyEncoding::Raw,
+                        other => {
+                            return Err(Error::msg(format!(
+                                "invalid pubkey_encoding `{other}`, expected base58 or raw"
+                            )))
+                        }
+                    }
+                }
+                "invalid_events" => {
+                    parsed.invalid_events = match value {
+                        "drop" => InvalidEvents::Drop,
+                        "emit" => InvalidEvents::Emit,
+                        "route" => InvalidEvents::Route,
+                        other => {
+                            return Err(Error::msg(format!(
+                                "invalid invalid_events `{other}`, expected drop, emit or route"
+                            )))
+                        }
+                    }
+                }
+                other => return Err(Error::msg(format!("unknown param `{other}`"))),
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_params_use_defaults() {
+        let params = Params::parse("").unwrap();
+        assert!(params.pubkey_encoding == PubkeyEncoding::Base58);
+        assert!(params.invalid_events == InvalidEvents::Route);
+    }
+
+    #[test]
+    fn parses_all_keys() {
+        let params = Params::parse(" pubkey_encoding = raw & invalid_events=emit&").unwrap();
+        assert!(params.pubkey_encoding == PubkeyEncoding::Raw);
+        assert!(params.invalid_events == InvalidEvents::Emit);
+
+        let params = Params::parse("invalid_events=drop").unwrap();
+        assert!(params.pubkey_encoding == PubkeyEncoding::Base58);
+        assert!(params.invalid_events == InvalidEvents::Drop);
+    }
+
+    #[test]
+    fn rejects_unknown_keys_and_values() {
+        assert!(Params::parse("pubkey_encoding=hex").is_err());
+        assert!(Params::parse("invalid_events=keep").is_err());
+        assert!(Params::parse("encoding=raw").is_err());
+        assert!(Params::parse("pubkey_encoding").is_err());
+    }
+}
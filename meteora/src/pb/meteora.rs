@@ -24,12 +24,12 @@ pub struct SwapEvent {
     /// 交易签名 (Base58)
     #[prost(string, tag="2")]
     pub signature: ::prost::alloc::string::String,
-    /// 交易发起者/Signer (Base58)
+    /// 交易发起者/Signer (Base58), 与 fee_payer 相同, 保留用于兼容
     #[prost(string, tag="3")]
     pub user: ::prost::alloc::string::String,
     /// ========== 平台与时间 ==========
     ///
-    /// 平台标识: "meteora_dlmm" / "meteora_damm" / "meteora_dbc"
+    /// 平台标识: "meteora_dlmm" / "meteora_damm" / "meteora_dbc" / "meteora_damm_v1"
     #[prost(string, tag="4")]
     pub platform: ::prost::alloc::string::String,
     /// 毫秒级时间戳
@@ -70,6 +70,589 @@ pub struct SwapEvent {
     /// Quote Token 精度
     #[prost(uint32, tag="14")]
     pub quote_decimals: u32,
+    /// ========== 指令路径 (用于块内排序) ==========
+    ///
+    /// 外层指令序号
+    #[prost(uint32, tag="15")]
+    pub instruction_index: u32,
+    /// 内层指令序号 (从 1 开始, 0 表示外层指令本身)
+    #[prost(uint32, tag="16")]
+    pub inner_instruction_index: u32,
+    /// ========== 原始字节 (参数 pubkey_encoding=raw 时填充, 对应的 Base58 字段留空) ==========
+    ///
+    /// 流动性池地址 (32 字节)
+    #[prost(bytes="vec", tag="17")]
+    pub pool_raw: ::prost::alloc::vec::Vec<u8>,
+    /// 交易签名 (64 字节)
+    #[prost(bytes="vec", tag="18")]
+    pub signature_raw: ::prost::alloc::vec::Vec<u8>,
+    /// 交易发起者/Signer (32 字节)
+    #[prost(bytes="vec", tag="19")]
+    pub user_raw: ::prost::alloc::vec::Vec<u8>,
+    /// Base Token Mint (32 字节)
+    #[prost(bytes="vec", tag="20")]
+    pub base_mint_raw: ::prost::alloc::vec::Vec<u8>,
+    /// Quote Token Mint (32 字节)
+    #[prost(bytes="vec", tag="21")]
+    pub quote_mint_raw: ::prost::alloc::vec::Vec<u8>,
+    /// ========== 解析诊断 ==========
+    ///
+    /// 金额解析结果, 非 STATUS_OK 时 mint 为空、金额为 0、方向为 SIDE_UNKNOWN
+    #[prost(enumeration="ParseStatus", tag="22")]
+    pub parse_status: i32,
+    /// 解析失败的具体原因 (STATUS_OK 时为空)
+    #[prost(string, tag="23")]
+    pub parse_reason: ::prost::alloc::string::String,
+    /// ========== 交易主体 ==========
+    ///
+    /// 手续费支付者 (第一个签名者, Base58)
+    #[prost(string, tag="24")]
+    pub fee_payer: ::prost::alloc::string::String,
+    /// 真实交易者: Swap 指令中用户 Token 账户的 owner (Base58), 无法解析时等于 fee_payer
+    #[prost(string, tag="25")]
+    pub trader: ::prost::alloc::string::String,
+    /// 手续费支付者 (32 字节, pubkey_encoding=raw)
+    #[prost(bytes="vec", tag="26")]
+    pub fee_payer_raw: ::prost::alloc::vec::Vec<u8>,
+    /// 真实交易者 (32 字节, pubkey_encoding=raw)
+    #[prost(bytes="vec", tag="27")]
+    pub trader_raw: ::prost::alloc::vec::Vec<u8>,
+    /// ========== Token-2022 (转账手续费 / 扩展) ==========
+    /// base_amount / quote_amount 为用户账户的余额变化; gross = 发送方转出数量, net = 接收方到账数量
+    /// 普通 SPL Token 或找不到对应转账时 gross = net = 用户侧金额, fee = 0
+    ///
+    /// Base Token 为 Token-2022 mint
+    #[prost(bool, tag="28")]
+    pub base_token_2022: bool,
+    /// Quote Token 为 Token-2022 mint
+    #[prost(bool, tag="29")]
+    pub quote_token_2022: bool,
+    /// Base 转账: 发送方转出数量 (含手续费)
+    #[prost(uint64, tag="30")]
+    pub base_gross_amount: u64,
+    /// Base 转账: 接收方到账数量
+    #[prost(uint64, tag="31")]
+    pub base_net_amount: u64,
+    /// Base 转账: 被 mint 代扣 (withheld) 的手续费
+    #[prost(uint64, tag="32")]
+    pub base_transfer_fee: u64,
+    /// Quote 转账: 发送方转出数量 (含手续费)
+    #[prost(uint64, tag="33")]
+    pub quote_gross_amount: u64,
+    /// Quote 转账: 接收方到账数量
+    #[prost(uint64, tag="34")]
+    pub quote_net_amount: u64,
+    /// Quote 转账: 被 mint 代扣 (withheld) 的手续费
+    #[prost(uint64, tag="35")]
+    pub quote_transfer_fee: u64,
+    /// Swap 中的 Token-2022 转账以 CPI 调用了 transfer hook 的 Execute 指令
+    #[prost(bool, tag="36")]
+    pub transfer_hook: bool,
+    /// Base 为 Token-2022、指令未声明手续费且由本条转账与接收方到账反推出非零 fee
+    #[prost(bool, tag="57")]
+    pub base_fee_inferred: bool,
+    /// Quote 为 Token-2022、指令未声明手续费且由本条转账与接收方到账反推出非零 fee
+    #[prost(bool, tag="58")]
+    pub quote_fee_inferred: bool,
+    /// ========== 路由归因 ==========
+    ///
+    /// 所在外层指令的程序 ID (Base58), 直接调用时为 Meteora 程序本身
+    #[prost(string, tag="37")]
+    pub outer_program_id: ::prost::alloc::string::String,
+    /// 外层程序分类: 直接调用 / 聚合器 / Bot
+    #[prost(enumeration="RouteSource", tag="38")]
+    pub route_source: i32,
+    /// CPI 深度 (stack_height - 1), 0 表示外层指令直接调用
+    #[prost(uint32, tag="39")]
+    pub cpi_depth: u32,
+    /// <signature>:<instruction_index>, 同一外层指令的多跳共享 (两种编码下都输出)
+    #[prost(string, tag="40")]
+    pub route_id: ::prost::alloc::string::String,
+    /// 外层程序 ID (32 字节, pubkey_encoding=raw)
+    #[prost(bytes="vec", tag="41")]
+    pub outer_program_id_raw: ::prost::alloc::vec::Vec<u8>,
+    /// ========== 执行成本 (交易级, 同一交易内的事件相同) ==========
+    ///
+    /// SetComputeUnitLimit 请求的 CU 上限, 未设置时为 0
+    #[prost(uint32, tag="42")]
+    pub compute_unit_limit: u32,
+    /// SetComputeUnitPrice 设置的 CU 单价 (micro-lamports), 未设置时为 0
+    #[prost(uint64, tag="43")]
+    pub compute_unit_price: u64,
+    /// 实际消耗的 CU (旧区块缺失时为 0)
+    #[prost(uint64, tag="44")]
+    pub compute_units_consumed: u64,
+    /// 交易总手续费 (lamports) = base_fee + priority_fee
+    #[prost(uint64, tag="45")]
+    pub fee: u64,
+    /// 签名费: 5000 lamports * 签名数
+    #[prost(uint64, tag="46")]
+    pub base_fee: u64,
+    /// 优先费 (lamports) = fee - base_fee
+    #[prost(uint64, tag="47")]
+    pub priority_fee: u64,
+    /// 转入 Jito tip 账户的 SOL 合计 (lamports)
+    #[prost(uint64, tag="48")]
+    pub jito_tip: u64,
+    /// ========== 交易意图 (Swap 指令参数) ==========
+    ///
+    /// exact_in / exact_out / partial_fill, 参数无法解码时为 INTENT_UNKNOWN
+    #[prost(enumeration="SwapIntent", tag="49")]
+    pub intent: i32,
+    /// exact_in: 输入数量; exact_out: 期望输出数量
+    #[prost(uint64, tag="50")]
+    pub requested_amount: u64,
+    /// exact_in: 最少输出 (min_amount_out); exact_out: 最多输入 (max_in_amount); 0 表示未设置
+    #[prost(uint64, tag="51")]
+    pub slippage_bound: u64,
+    /// DLMM swap_with_price_impact 的最大价格冲击 (bps), 其它指令为 0
+    #[prost(uint32, tag="52")]
+    pub max_price_impact_bps: u32,
+    /// 实际成交距离滑点边界的余量 (bps), 未设置边界时为 0
+    #[prost(uint32, tag="53")]
+    pub slippage_headroom_bps: u32,
+    /// ========== 池子状态 (交易后) ==========
+    ///
+    /// 金额解析失败或平台无法取得储备 (DAMM V1) 时为空
+    #[prost(message, optional, tag="54")]
+    pub pool_state: ::core::option::Option<PoolState>,
+    /// ========== 手续费 (取自各程序的 Swap 事件, 原始精度) ==========
+    ///
+    /// 找不到 Swap 事件 (如旧区块缺失内层指令或日志被截断) 时为空
+    #[prost(message, optional, tag="55")]
+    pub fees: ::core::option::Option<FeeAmounts>,
+    /// 手续费以 quote token 计, 否则以 base token 计
+    #[prost(bool, tag="56")]
+    pub fee_in_quote: bool,
+    /// ========== 成交前价格 ==========
+    ///
+    /// 成交前的池子价格 (quote / base, 按精度换算): DLMM 为 start_bin 价格, DAMM V2 为交易前储备隐含价格, 其它平台为 0
+    #[prost(double, tag="59")]
+    pub price_before: f64,
+}
+/// ============================================================================
+/// 手续费拆分 (原始精度, 同一 token)
+/// ============================================================================
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeAmounts {
+    /// 各项之和
+    #[prost(uint64, tag="1")]
+    pub total_fee: u64,
+    /// 归 LP: DLMM fee - protocol - host / DAMM V2 lp_fee / DAMM V1 trade_fee
+    #[prost(uint64, tag="2")]
+    pub lp_fee: u64,
+    /// 归协议
+    #[prost(uint64, tag="3")]
+    pub protocol_fee: u64,
+    /// DAMM V2: 归池子 partner
+    #[prost(uint64, tag="4")]
+    pub partner_fee: u64,
+    /// DLMM / DAMM V1 host_fee, DAMM V2 / DBC referral_fee
+    #[prost(uint64, tag="5")]
+    pub referral_fee: u64,
+    /// DBC: 归 partner 与 creator 的交易手续费 (分成比例在 config 中, 不在交易数据里)
+    #[prost(uint64, tag="6")]
+    pub trading_fee: u64,
+}
+/// ============================================================================
+/// 交易后的池子状态 (取自 vault 的 post_token_balances 与 Swap 事件), 另附交易前价格
+/// ============================================================================
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolState {
+    /// Base vault 余额 (原始精度)
+    #[prost(uint64, tag="1")]
+    pub base_reserve: u64,
+    /// Quote vault 余额 (原始精度)
+    #[prost(uint64, tag="2")]
+    pub quote_reserve: u64,
+    /// 储备隐含价格: quote_reserve / base_reserve (按精度换算), 恒定乘积池之外仅为近似
+    #[prost(double, tag="3")]
+    pub spot_price: f64,
+    /// DLMM: Swap 事件的 end_bin_id
+    #[prost(int32, optional, tag="4")]
+    pub active_bin_id: ::core::option::Option<i32>,
+    /// DLMM: 由单 bin 成交反推的 bin_step (bps), 无法推断时为 0
+    #[prost(uint32, tag="5")]
+    pub bin_step: u32,
+    /// DLMM: active bin 价格 (quote / base, 按精度换算), bin_step 未知时为 0
+    #[prost(double, tag="6")]
+    pub bin_price: f64,
+    /// 交易前储备隐含价格 (取自 vault 的 pre_token_balances), 任一侧无记录时为 0
+    #[prost(double, tag="7")]
+    pub spot_price_before: f64,
+    /// DLMM: start_bin 价格 (交易前 active bin), bin_step 未知时为 0
+    #[prost(double, tag="8")]
+    pub bin_price_before: f64,
+}
+/// ============================================================================
+/// 失败交易中的 Swap (meteora_failed_swaps 模块)
+/// ============================================================================
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FailedSwaps {
+    #[prost(message, repeated, tag="1")]
+    pub failed_swaps: ::prost::alloc::vec::Vec<FailedSwap>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FailedSwap {
+    /// ========== 定位字段 ==========
+    ///
+    /// 流动性池地址 (Base58)
+    #[prost(string, tag="1")]
+    pub pool: ::prost::alloc::string::String,
+    /// 交易签名 (Base58)
+    #[prost(string, tag="2")]
+    pub signature: ::prost::alloc::string::String,
+    /// 交易发起者/Signer (Base58)
+    #[prost(string, tag="3")]
+    pub user: ::prost::alloc::string::String,
+    /// 平台标识: "meteora_dlmm" / "meteora_damm" / "meteora_dbc"
+    #[prost(string, tag="4")]
+    pub platform: ::prost::alloc::string::String,
+    /// 毫秒级时间戳
+    #[prost(uint64, tag="5")]
+    pub timestamp_ms: u64,
+    /// 区块高度
+    #[prost(uint64, tag="6")]
+    pub slot: u64,
+    /// 交易在区块中的序号
+    #[prost(uint32, tag="7")]
+    pub tx_index: u32,
+    /// Swap 所在外层指令序号
+    #[prost(uint32, tag="8")]
+    pub instruction_index: u32,
+    /// 内层指令序号 (0 表示外层指令本身)
+    #[prost(uint32, tag="9")]
+    pub inner_instruction_index: u32,
+    /// ========== 错误 (由 meta.err 的 bincode 字节解码) ==========
+    ///
+    /// TransactionError 变体, 如 "InstructionError"
+    #[prost(string, tag="10")]
+    pub transaction_error: ::prost::alloc::string::String,
+    /// 出错的外层指令序号 (仅 InstructionError)
+    #[prost(uint32, optional, tag="11")]
+    pub failed_instruction_index: ::core::option::Option<u32>,
+    /// InstructionError 变体, 如 "Custom" / "ComputationalBudgetExceeded"
+    #[prost(string, tag="12")]
+    pub instruction_error: ::prost::alloc::string::String,
+    /// InstructionError::Custom 错误码
+    #[prost(uint32, optional, tag="13")]
+    pub custom_code: ::core::option::Option<u32>,
+    /// 最先报错的程序 (取自日志, Base58), 日志缺失时为空
+    #[prost(string, tag="14")]
+    pub failed_program_id: ::prost::alloc::string::String,
+    /// 错误名, 如 "ExceededAmountSlippageTolerance"; 非 Custom 错误同 instruction_error / transaction_error, 未收录的错误码为空
+    #[prost(string, tag="15")]
+    pub error_name: ::prost::alloc::string::String,
+    /// 出错的外层指令即该 Swap 所在的外层指令
+    #[prost(bool, tag="16")]
+    pub failed_in_swap: bool,
+    /// ========== 成本 ==========
+    ///
+    /// 实际消耗的 CU (旧区块缺失时为 0)
+    #[prost(uint64, tag="17")]
+    pub compute_units_consumed: u64,
+    /// 交易手续费 (lamports), 失败交易同样扣除
+    #[prost(uint64, tag="18")]
+    pub fee: u64,
+}
+/// ============================================================================
+/// DAMM V1 流动性变动 (meteora_liquidity 模块)
+/// ============================================================================
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityEvents {
+    #[prost(message, repeated, tag="1")]
+    pub events: ::prost::alloc::vec::Vec<LiquidityEvent>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityEvent {
+    /// ========== 定位字段 ==========
+    ///
+    /// 流动性池地址 (Base58)
+    #[prost(string, tag="1")]
+    pub pool: ::prost::alloc::string::String,
+    /// 交易签名 (Base58)
+    #[prost(string, tag="2")]
+    pub signature: ::prost::alloc::string::String,
+    /// 交易发起者/Signer (Base58)
+    #[prost(string, tag="3")]
+    pub user: ::prost::alloc::string::String,
+    /// 平台标识: "meteora_damm_v1"
+    #[prost(string, tag="4")]
+    pub platform: ::prost::alloc::string::String,
+    /// 毫秒级时间戳
+    #[prost(uint64, tag="5")]
+    pub timestamp_ms: u64,
+    /// 区块高度
+    #[prost(uint64, tag="6")]
+    pub slot: u64,
+    /// 交易在区块中的序号
+    #[prost(uint32, tag="7")]
+    pub tx_index: u32,
+    /// 外层指令序号
+    #[prost(uint32, tag="8")]
+    pub instruction_index: u32,
+    /// 内层指令序号 (0 表示外层指令本身)
+    #[prost(uint32, tag="9")]
+    pub inner_instruction_index: u32,
+    /// ========== 变动 ==========
+    ///
+    /// 添加 / 移除
+    #[prost(enumeration="LiquidityAction", tag="10")]
+    pub action: i32,
+    /// IDL 指令名, 如 "add_balance_liquidity" / "remove_liquidity_single_side"
+    #[prost(string, tag="11")]
+    pub instruction: ::prost::alloc::string::String,
+    /// ========== 金额 (原始精度, 取自进出 vault token 账户的转账) ==========
+    ///
+    /// Token A Mint (Base58)
+    #[prost(string, tag="12")]
+    pub token_a_mint: ::prost::alloc::string::String,
+    /// Token B Mint (Base58)
+    #[prost(string, tag="13")]
+    pub token_b_mint: ::prost::alloc::string::String,
+    /// Token A 数量
+    #[prost(uint64, tag="14")]
+    pub token_a_amount: u64,
+    /// Token B 数量
+    #[prost(uint64, tag="15")]
+    pub token_b_amount: u64,
+    /// Token A 精度
+    #[prost(uint32, tag="16")]
+    pub token_a_decimals: u32,
+    /// Token B 精度
+    #[prost(uint32, tag="17")]
+    pub token_b_decimals: u32,
+    /// 池子 LP Mint (Base58)
+    #[prost(string, tag="18")]
+    pub lp_mint: ::prost::alloc::string::String,
+    /// 铸造 (添加) 或销毁 (移除) 的池子 LP 数量
+    #[prost(uint64, tag="19")]
+    pub lp_amount: u64,
+}
+/// ============================================================================
+/// DLMM 池子事件 (meteora_dlmm_events 模块, 取自 emit_cpi! 事件)
+/// ============================================================================
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DlmmPairEvents {
+    #[prost(message, repeated, tag="1")]
+    pub events: ::prost::alloc::vec::Vec<DlmmPairEvent>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DlmmPairEvent {
+    /// ========== 定位字段 ==========
+    ///
+    /// DLMM 池子地址 (Base58)
+    #[prost(string, tag="1")]
+    pub lb_pair: ::prost::alloc::string::String,
+    /// 交易签名 (Base58)
+    #[prost(string, tag="2")]
+    pub signature: ::prost::alloc::string::String,
+    /// 毫秒级时间戳
+    #[prost(uint64, tag="3")]
+    pub timestamp_ms: u64,
+    /// 区块高度
+    #[prost(uint64, tag="4")]
+    pub slot: u64,
+    /// 交易在区块中的序号
+    #[prost(uint32, tag="5")]
+    pub tx_index: u32,
+    /// 外层指令序号
+    #[prost(uint32, tag="6")]
+    pub instruction_index: u32,
+    /// 事件 CPI 的内层指令序号
+    #[prost(uint32, tag="7")]
+    pub inner_instruction_index: u32,
+    /// 区块内顺序 ((tx_index << 32) | (展开后的指令序号 + 1)), 作为 store 写入序号
+    #[prost(uint64, tag="8")]
+    pub ordinal: u64,
+    /// 事件类型, 决定下列字段中哪些有效
+    #[prost(enumeration="DlmmEventKind", tag="9")]
+    pub kind: i32,
+    /// ========== Swap / GoToABin ==========
+    ///
+    /// 移动前的 active bin (GoToABin: from_bin_id)
+    #[prost(int32, tag="10")]
+    pub start_bin_id: i32,
+    /// 移动后的 active bin (GoToABin: to_bin_id)
+    #[prost(int32, tag="11")]
+    pub end_bin_id: i32,
+    /// Swap 事件的 fee_bps (超出 u64 时截断)
+    #[prost(uint64, tag="12")]
+    pub fee_bps: u64,
+    /// ========== LbPairCreate (Swap 由单 bin 成交反推) ==========
+    ///
+    /// bin_step (bps), 未知时为 0
+    #[prost(uint32, tag="13")]
+    pub bin_step: u32,
+    /// ========== FeeParameterUpdate ==========
+    ///
+    /// 基础费率系数
+    #[prost(uint32, tag="14")]
+    pub base_factor: u32,
+    /// 协议分成 (bps)
+    #[prost(uint32, tag="15")]
+    pub protocol_share: u32,
+    /// ========== DynamicFeeParameterUpdate ==========
+    ///
+    /// 高频窗口 (秒): 间隔小于此值时沿用参考值
+    #[prost(uint32, tag="16")]
+    pub filter_period: u32,
+    /// 衰减窗口 (秒): 间隔超过此值时 volatility_reference 归零
+    #[prost(uint32, tag="17")]
+    pub decay_period: u32,
+    /// 衰减系数 (bps)
+    #[prost(uint32, tag="18")]
+    pub reduction_factor: u32,
+    /// 动态费率系数
+    #[prost(uint32, tag="19")]
+    pub variable_fee_control: u32,
+    /// volatility_accumulator 上限
+    #[prost(uint32, tag="20")]
+    pub max_volatility_accumulator: u32,
+}
+/// ============================================================================
+/// DLMM 池子状态 (meteora_dlmm_pair_states 模块, active bin 移动时输出)
+/// 参数来自 store_meteora_dlmm_pairs，只有在流中出现过对应事件的池子才有值
+/// ============================================================================
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DlmmPairStates {
+    #[prost(message, repeated, tag="1")]
+    pub states: ::prost::alloc::vec::Vec<DlmmPairState>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DlmmPairState {
+    /// ========== 定位字段 ==========
+    ///
+    /// DLMM 池子地址 (Base58)
+    #[prost(string, tag="1")]
+    pub lb_pair: ::prost::alloc::string::String,
+    /// 交易签名 (Base58)
+    #[prost(string, tag="2")]
+    pub signature: ::prost::alloc::string::String,
+    /// 毫秒级时间戳
+    #[prost(uint64, tag="3")]
+    pub timestamp_ms: u64,
+    /// 区块高度
+    #[prost(uint64, tag="4")]
+    pub slot: u64,
+    /// 交易在区块中的序号
+    #[prost(uint32, tag="5")]
+    pub tx_index: u32,
+    /// 外层指令序号
+    #[prost(uint32, tag="6")]
+    pub instruction_index: u32,
+    /// 事件 CPI 的内层指令序号
+    #[prost(uint32, tag="7")]
+    pub inner_instruction_index: u32,
+    /// 引起移动的事件: Swap / GoToABin
+    #[prost(enumeration="DlmmEventKind", tag="8")]
+    pub kind: i32,
+    /// ========== Active bin ==========
+    ///
+    /// 移动前的 active bin
+    #[prost(int32, tag="9")]
+    pub previous_active_id: i32,
+    /// 移动后的 active bin
+    #[prost(int32, tag="10")]
+    pub active_id: i32,
+    /// |active_id - previous_active_id|
+    #[prost(uint32, tag="11")]
+    pub bins_crossed: u32,
+    /// 上一笔 Swap 的时间, 未知时为 0
+    #[prost(uint64, tag="12")]
+    pub previous_swap_timestamp_ms: u64,
+    /// ========== 费率 ==========
+    ///
+    /// bin_step (bps), 未知时为 0
+    #[prost(uint32, tag="13")]
+    pub bin_step: u32,
+    /// 基础费率系数, 未知时为 0
+    #[prost(uint32, tag="14")]
+    pub base_factor: u32,
+    /// 基础费率 = base_factor * bin_step / 10000 (bps), 参数未知时为 0
+    #[prost(double, tag="15")]
+    pub base_fee_bps: f64,
+    /// 协议分成 (bps), 未知时为 0
+    #[prost(uint32, tag="16")]
+    pub protocol_share: u32,
+    /// Swap 事件的 fee_bps (GoToABin 为 0)
+    #[prost(uint64, tag="17")]
+    pub fee_bps: u64,
+    /// ========== 动态费率参数 (DynamicFeeParameterUpdate, 未知时为 0) ==========
+    ///
+    /// 高频窗口 (秒)
+    #[prost(uint32, tag="18")]
+    pub filter_period: u32,
+    /// 衰减窗口 (秒)
+    #[prost(uint32, tag="19")]
+    pub decay_period: u32,
+    /// 衰减系数 (bps)
+    #[prost(uint32, tag="20")]
+    pub reduction_factor: u32,
+    /// 动态费率系数
+    #[prost(uint32, tag="21")]
+    pub variable_fee_control: u32,
+    /// volatility_accumulator 上限
+    #[prost(uint32, tag="22")]
+    pub max_volatility_accumulator: u32,
+    /// ========== 波动率 (按合约规则由 Swap 序列推算, 无法确定时为空) ==========
+    ///
+    /// 参考 bin: 距上一笔 Swap 超过 filter_period 时重置为移动前的 active bin
+    #[prost(int32, optional, tag="23")]
+    pub index_reference: ::core::option::Option<i32>,
+    /// 参考波动率
+    #[prost(uint32, optional, tag="24")]
+    pub volatility_reference: ::core::option::Option<u32>,
+    /// 本次 Swap 结束时的波动率累加值
+    #[prost(uint32, optional, tag="25")]
+    pub volatility_accumulator: ::core::option::Option<u32>,
+    /// 由 volatility_accumulator 计算的动态费率 (bps)
+    #[prost(double, optional, tag="26")]
+    pub variable_fee_bps: ::core::option::Option<f64>,
+}
+/// ============================================================================
+/// 手续费累计 (meteora_fee_snapshots 模块, 由各手续费 store 的 deltas 生成)
+/// ============================================================================
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeSnapshots {
+    #[prost(message, repeated, tag="1")]
+    pub snapshots: ::prost::alloc::vec::Vec<FeeSnapshot>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeSnapshot {
+    /// 累计维度
+    #[prost(enumeration="FeeScope", tag="1")]
+    pub scope: i32,
+    /// SCOPE_POOL: 流动性池地址 (Base58)
+    #[prost(string, tag="2")]
+    pub pool: ::prost::alloc::string::String,
+    /// SCOPE_PLATFORM / SCOPE_DAY: 平台标识
+    #[prost(string, tag="3")]
+    pub platform: ::prost::alloc::string::String,
+    /// SCOPE_DAY: UTC 日期 (YYYY-MM-DD)
+    #[prost(string, tag="4")]
+    pub day: ::prost::alloc::string::String,
+    /// 手续费 token 的 Mint (Base58)
+    #[prost(string, tag="5")]
+    pub fee_mint: ::prost::alloc::string::String,
+    /// 本区块新增
+    #[prost(message, optional, tag="6")]
+    pub delta: ::core::option::Option<FeeAmounts>,
+    /// 累计至本区块
+    #[prost(message, optional, tag="7")]
+    pub total: ::core::option::Option<FeeAmounts>,
 }
 /// ============================================================================
 /// 交易方向
@@ -105,4 +688,261 @@ impl SwapSide {
         }
     }
 }
+/// ============================================================================
+/// 交易意图
+/// ============================================================================
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SwapIntent {
+    IntentUnknown = 0,
+    /// 固定输入, 限定最少输出
+    IntentExactIn = 1,
+    /// 固定输出, 限定最多输入
+    IntentExactOut = 2,
+    /// 固定输入, 流动性不足时允许部分成交 (DAMM V2 / DBC swap2)
+    IntentPartialFill = 3,
+}
+impl SwapIntent {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SwapIntent::IntentUnknown => "INTENT_UNKNOWN",
+            SwapIntent::IntentExactIn => "INTENT_EXACT_IN",
+            SwapIntent::IntentExactOut => "INTENT_EXACT_OUT",
+            SwapIntent::IntentPartialFill => "INTENT_PARTIAL_FILL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "INTENT_UNKNOWN" => Some(Self::IntentUnknown),
+            "INTENT_EXACT_IN" => Some(Self::IntentExactIn),
+            "INTENT_EXACT_OUT" => Some(Self::IntentExactOut),
+            "INTENT_PARTIAL_FILL" => Some(Self::IntentPartialFill),
+            _ => None,
+        }
+    }
+}
+/// ============================================================================
+/// 流动性变动方向
+/// ============================================================================
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum LiquidityAction {
+    ActionUnknown = 0,
+    /// 添加流动性
+    ActionDeposit = 1,
+    /// 移除流动性
+    ActionWithdraw = 2,
+}
+impl LiquidityAction {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            LiquidityAction::ActionUnknown => "ACTION_UNKNOWN",
+            LiquidityAction::ActionDeposit => "ACTION_DEPOSIT",
+            LiquidityAction::ActionWithdraw => "ACTION_WITHDRAW",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ACTION_UNKNOWN" => Some(Self::ActionUnknown),
+            "ACTION_DEPOSIT" => Some(Self::ActionDeposit),
+            "ACTION_WITHDRAW" => Some(Self::ActionWithdraw),
+            _ => None,
+        }
+    }
+}
+/// ============================================================================
+/// DLMM 事件类型
+/// ============================================================================
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum DlmmEventKind {
+    KindUnknown = 0,
+    /// Swap: active bin 随成交移动
+    KindSwap = 1,
+    /// LbPairCreate: 池子创建, 带 bin_step
+    KindPairCreate = 2,
+    /// FeeParameterUpdate: base_factor / protocol_share
+    KindFeeParameterUpdate = 3,
+    /// DynamicFeeParameterUpdate: 动态费率参数
+    KindDynamicFeeParameterUpdate = 4,
+    /// GoToABin: 无成交地移动 active bin
+    KindGoToABin = 5,
+}
+impl DlmmEventKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            DlmmEventKind::KindUnknown => "KIND_UNKNOWN",
+            DlmmEventKind::KindSwap => "KIND_SWAP",
+            DlmmEventKind::KindPairCreate => "KIND_PAIR_CREATE",
+            DlmmEventKind::KindFeeParameterUpdate => "KIND_FEE_PARAMETER_UPDATE",
+            DlmmEventKind::KindDynamicFeeParameterUpdate => {
+                "KIND_DYNAMIC_FEE_PARAMETER_UPDATE"
+            }
+            DlmmEventKind::KindGoToABin => "KIND_GO_TO_A_BIN",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "KIND_UNKNOWN" => Some(Self::KindUnknown),
+            "KIND_SWAP" => Some(Self::KindSwap),
+            "KIND_PAIR_CREATE" => Some(Self::KindPairCreate),
+            "KIND_FEE_PARAMETER_UPDATE" => Some(Self::KindFeeParameterUpdate),
+            "KIND_DYNAMIC_FEE_PARAMETER_UPDATE" => {
+                Some(Self::KindDynamicFeeParameterUpdate)
+            }
+            "KIND_GO_TO_A_BIN" => Some(Self::KindGoToABin),
+            _ => None,
+        }
+    }
+}
+/// ============================================================================
+/// 手续费累计维度
+/// ============================================================================
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum FeeScope {
+    ScopeUnknown = 0,
+    /// 按池子 (store_meteora_pool_fees)
+    ScopePool = 1,
+    /// 按平台 (store_meteora_platform_fees)
+    ScopePlatform = 2,
+    /// 按 UTC 日期与平台 (store_meteora_daily_fees)
+    ScopeDay = 3,
+}
+impl FeeScope {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            FeeScope::ScopeUnknown => "SCOPE_UNKNOWN",
+            FeeScope::ScopePool => "SCOPE_POOL",
+            FeeScope::ScopePlatform => "SCOPE_PLATFORM",
+            FeeScope::ScopeDay => "SCOPE_DAY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SCOPE_UNKNOWN" => Some(Self::ScopeUnknown),
+            "SCOPE_POOL" => Some(Self::ScopePool),
+            "SCOPE_PLATFORM" => Some(Self::ScopePlatform),
+            "SCOPE_DAY" => Some(Self::ScopeDay),
+            _ => None,
+        }
+    }
+}
+/// ============================================================================
+/// 路由来源
+/// ============================================================================
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum RouteSource {
+    /// 未识别的外层程序
+    RouteUnknown = 0,
+    /// 外层指令直接调用 Meteora
+    RouteDirect = 1,
+    /// Jupiter v6
+    RouteJupiter = 2,
+    /// OKX DEX Router
+    RouteOkx = 3,
+    /// Raydium 路由
+    RouteRaydium = 4,
+    /// 已知交易 Bot 程序
+    RouteBot = 5,
+}
+impl RouteSource {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            RouteSource::RouteUnknown => "ROUTE_UNKNOWN",
+            RouteSource::RouteDirect => "ROUTE_DIRECT",
+            RouteSource::RouteJupiter => "ROUTE_JUPITER",
+            RouteSource::RouteOkx => "ROUTE_OKX",
+            RouteSource::RouteRaydium => "ROUTE_RAYDIUM",
+            RouteSource::RouteBot => "ROUTE_BOT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ROUTE_UNKNOWN" => Some(Self::RouteUnknown),
+            "ROUTE_DIRECT" => Some(Self::RouteDirect),
+            "ROUTE_JUPITER" => Some(Self::RouteJupiter),
+            "ROUTE_OKX" => Some(Self::RouteOkx),
+            "ROUTE_RAYDIUM" => Some(Self::RouteRaydium),
+            "ROUTE_BOT" => Some(Self::RouteBot),
+            _ => None,
+        }
+    }
+}
+/// ============================================================================
+/// 金额解析结果
+/// ============================================================================
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ParseStatus {
+    StatusOk = 0,
+    /// 用户 Token 账户与 SOL 余额均无可用变化
+    StatusNoBalanceChange = 1,
+    /// 付出与收到的 mint 相同
+    StatusSameMint = 2,
+    /// 指令 accounts 或账户索引越界
+    StatusAccountIndexOutOfRange = 3,
+    /// v0 交易引用了 ALT，但区块数据缺少加载后的地址
+    StatusAltMissing = 4,
+    /// 余额变化超出 u64 范围
+    StatusAmountOverflow = 5,
+}
+impl ParseStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ParseStatus::StatusOk => "STATUS_OK",
+            ParseStatus::StatusNoBalanceChange => "STATUS_NO_BALANCE_CHANGE",
+            ParseStatus::StatusSameMint => "STATUS_SAME_MINT",
+            ParseStatus::StatusAccountIndexOutOfRange => {
+                "STATUS_ACCOUNT_INDEX_OUT_OF_RANGE"
+            }
+            ParseStatus::StatusAltMissing => "STATUS_ALT_MISSING",
+            ParseStatus::StatusAmountOverflow => "STATUS_AMOUNT_OVERFLOW",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "STATUS_OK" => Some(Self::StatusOk),
+            "STATUS_NO_BALANCE_CHANGE" => Some(Self::StatusNoBalanceChange),
+            "STATUS_SAME_MINT" => Some(Self::StatusSameMint),
+            "STATUS_ACCOUNT_INDEX_OUT_OF_RANGE" => {
+                Some(Self::StatusAccountIndexOutOfRange)
+            }
+            "STATUS_ALT_MISSING" => Some(Self::StatusAltMissing),
+            "STATUS_AMOUNT_OVERFLOW" => Some(Self::StatusAmountOverflow),
+            _ => None,
+        }
+    }
+}
 // @@protoc_insertion_point(module)
@@ -0,0 +1,121 @@
+use crate::dlmm::{find_dlmm_swap, infer_bin_step};
+use crate::pb::meteora::PoolState;
+use crate::SwapAmounts;
+use dex_common::account::AccountKeys;
+use dex_common::balance::{pre_token_amount, token_amount};
+use dex_common::instruction::FlattenedInstruction;
+use dex_common::price::{reserve_price, ui_price};
+use substreams_solana::pb::sf::solana::r#type::v1::TransactionStatusMeta;
+
+/// DLMM swap 账户: token_x_mint (6)
+const DLMM_TOKEN_X_MINT_POSITION: usize = 6;
+
+/// 池子 vault 账户在 Swap 指令 accounts 中的位置 (根据 IDL)
+/// DAMM V1 的 vault token 账户由同一 mint 的所有池子共享，无法得到单个池子的储备
+fn reserve_account_positions(platform: &str) -> Option<[usize; 2]> {
+    match platform {
+        // DLMM: reserve_x (2), reserve_y (3)
+        "meteora_dlmm" => Some([2, 3]),
+        // DAMM V2: token_a_vault (4), token_b_vault (5)
+        "meteora_damm" => Some([4, 5]),
+        // DBC: base_vault (5), quote_vault (6)
+        "meteora_dbc" => Some([5, 6]),
+        _ => None,
+    }
+}
+
+/// 交易后的池子状态: vault 储备、储备隐含价格，DLMM 另附 active bin 与 bin 价格
+/// 同时给出交易前的价格 (vault 的 pre_token_balances / DLMM start_bin_id)，用于夹子受害者损失等以成交前价格为参考的计算
+/// 金额解析失败 (mint 未知) 或平台无法取得储备时返回 None
+pub fn pool_state(
+    meta: &TransactionStatusMeta,
+    account_keys: &AccountKeys,
+    children: &[FlattenedInstruction],
+    inst_accounts: &[u8],
+    platform: &str,
+    amounts: &SwapAmounts,
+) -> Option<PoolState> {
+    if amounts.base_mint.is_empty() {
+        return None;
+    }
+    let positions = reserve_account_positions(platform)?;
+
+    let mut state = PoolState::default();
+    let (mut base_reserve_before, mut quote_reserve_before) = (0, 0);
+    for &position in positions.iter() {
+        let index = *inst_accounts.get(position)? as u32;
+        let balance = match meta
+            .post_token_balances
+            .iter()
+            .find(|b| b.account_index == index)
+        {
+            Some(balance) => balance,
+            None => continue,
+        };
+        let reserve = u64::try_from(token_amount(Some(balance))).unwrap_or(0);
+        if balance.mint == amounts.base_mint {
+            state.base_reserve = reserve;
+            base_reserve_before = pre_token_amount(meta, index);
+        } else if balance.mint == amounts.quote_mint {
+            state.quote_reserve = reserve;
+            quote_reserve_before = pre_token_amount(meta, index);
+        }
+    }
+
+    state.spot_price = reserve_price(
+        state.base_reserve,
+        state.quote_reserve,
+        amounts.base_decimals,
+        amounts.quote_decimals,
+    );
+    state.spot_price_before = reserve_price(
+        base_reserve_before,
+        quote_reserve_before,
+        amounts.base_decimals,
+        amounts.quote_decimals,
+    );
+
+    if platform == "meteora_dlmm" {
+        if let Some(swap) = find_dlmm_swap(account_keys, children) {
+            state.active_bin_id = Some(swap.end_bin_id);
+
+            if let Some(bin_step) = infer_bin_step(&swap) {
+                state.bin_step = bin_step;
+                let base_is_x = inst_accounts
+                    .get(DLMM_TOKEN_X_MINT_POSITION)
+                    .and_then(|&index| account_keys.get(index as usize))
+                    .is_some_and(|mint| bs58::encode(mint).into_string() == amounts.base_mint);
+                state.bin_price = bin_price(bin_step, swap.end_bin_id, base_is_x, amounts);
+                state.bin_price_before = bin_price(bin_step, swap.start_bin_id, base_is_x, amounts);
+            }
+        }
+    }
+
+    Some(state)
+}
+
+/// 成交前的池子价格 (quote / base，按精度换算)，无法取得时为 0
+/// DLMM 取 start_bin 价格；DAMM V2 取交易前储备隐含价格 (非全区间流动性时为近似)
+/// DBC 的 vault 余额不反映曲线价格，DAMM V1 没有单池储备，均为 0
+pub fn price_before(platform: &str, state: Option<&PoolState>) -> f64 {
+    match (platform, state) {
+        ("meteora_dlmm", Some(state)) => state.bin_price_before,
+        ("meteora_damm", Some(state)) => state.spot_price_before,
+        _ => 0.0,
+    }
+}
+
+/// bin 价格 = (1 + bin_step / 10000) ^ bin_id (y / x 原始单位)，换算为 quote / base 按精度的价格
+fn bin_price(bin_step: u32, bin_id: i32, base_is_x: bool, amounts: &SwapAmounts) -> f64 {
+    let raw_y_per_x = (1.0 + bin_step as f64 / 10_000.0).powi(bin_id);
+    let raw_quote_per_base = if base_is_x {
+        raw_y_per_x
+    } else {
+        1.0 / raw_y_per_x
+    };
+    ui_price(
+        raw_quote_per_base,
+        amounts.base_decimals,
+        amounts.quote_decimals,
+    )
+}
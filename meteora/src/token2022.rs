@@ -0,0 +1,137 @@
+use crate::constant::{TOKEN_2022_PROGRAM, TOKEN_2022_PROGRAM_ID, TRANSFER_HOOK_EXECUTE};
+use dex_common::account::AccountKeys;
+use dex_common::balance::token_amount;
+use dex_common::instruction::{get_child_instructions, FlattenedInstruction};
+use dex_common::transfer::parse_token_transfer;
+use substreams_solana::pb::sf::solana::r#type::v1::TransactionStatusMeta;
+
+/// Swap 中经过用户 Token 账户的一条转账 (用户转出或转入)
+/// gross = 发送方转出数量，net = 接收方到账数量，transfer_fee = 被 mint 代扣的手续费
+#[derive(Default)]
+pub struct TransferLeg {
+    pub token_2022: bool,
+    pub gross_amount: u64,
+    pub net_amount: u64,
+    pub transfer_fee: u64,
+    /// Token-2022 转账未在指令中声明手续费，fee 由本条转账的数量与接收方实际到账反推得到 (且大于 0)
+    pub fee_inferred: bool,
+    /// 该转账以 CPI 调用了 transfer hook 程序的 Execute 指令
+    pub transfer_hook: bool,
+}
+
+/// 根据 TokenBalance.program_id 判断账户是否属于 Token-2022
+pub fn is_token_2022_account(meta: &TransactionStatusMeta, account_index: u32) -> bool {
+    meta.pre_token_balances
+        .iter()
+        .chain(meta.post_token_balances.iter())
+        .any(|b| b.account_index == account_index && b.program_id == TOKEN_2022_PROGRAM)
+}
+
+/// 在 Swap 子指令中查找经过用户账户的转账，计算 gross / net / fee
+/// user_is_source: 用户为发送方 (输入腿)，否则为接收方 (输出腿)
+/// user_amount: 用户账户的余额变化，找不到转账时 gross = net = user_amount
+/// instructions 为整笔交易展开后的指令，用于判断接收方是否只被这一条转账改动
+pub fn find_transfer_leg(
+    meta: &TransactionStatusMeta,
+    account_keys: &AccountKeys,
+    instructions: &[FlattenedInstruction],
+    children: &[FlattenedInstruction],
+    user_account: u32,
+    user_is_source: bool,
+    user_amount: u64,
+) -> TransferLeg {
+    let mut leg = TransferLeg {
+        token_2022: is_token_2022_account(meta, user_account),
+        gross_amount: user_amount,
+        net_amount: user_amount,
+        transfer_fee: 0,
+        fee_inferred: false,
+        transfer_hook: false,
+    };
+
+    let found = children.iter().enumerate().find_map(|(position, child)| {
+        let program_id = account_keys.get(child.program_id_index as usize)?;
+        let transfer = parse_token_transfer(program_id, child.accounts, child.data)?;
+        let matched = if user_is_source {
+            transfer.source == user_account
+        } else {
+            transfer.destination == user_account
+        };
+        matched.then_some((position, program_id, transfer))
+    });
+
+    let (position, program_id, transfer) = match found {
+        Some(found) => found,
+        None => return leg,
+    };
+
+    let is_token_2022 = program_id == TOKEN_2022_PROGRAM_ID.0.as_slice();
+    leg.token_2022 |= is_token_2022;
+    leg.gross_amount = transfer.amount;
+
+    // 手续费: TransferCheckedWithFee 在指令数据中显式声明，直接使用
+    // 普通 TransferChecked 由 mint 的 TransferFeeConfig 计算，该账户数据不在区块中，
+    // 只能由本条转账的数量与接收方到账反推: 仅当接收方在整笔交易中只被这一条转账改动时，
+    // 其余额增量才是本条转账的到账数量，否则不反推 (手续费记为 0)
+    let net_amount = match transfer.fee {
+        Some(fee) => transfer.amount.saturating_sub(fee),
+        None if is_token_2022 => {
+            credited_amount(meta, account_keys, instructions, transfer.destination)
+                .filter(|&credited| credited <= transfer.amount)
+                .unwrap_or(transfer.amount)
+        }
+        None => transfer.amount,
+    };
+    leg.net_amount = net_amount;
+    leg.transfer_fee = transfer.amount - net_amount;
+    leg.fee_inferred = transfer.fee.is_none() && leg.transfer_fee > 0;
+
+    // transfer hook: 该转账的 CPI 子指令中有 hook 接口的 Execute 指令
+    // 缺少 stack_height 时子指令退化为 Swap 内后续全部内层指令，仍限定在同一 Swap 中
+    leg.transfer_hook = is_token_2022
+        && get_child_instructions(children, position)
+            .iter()
+            .any(|child| child.data.starts_with(&TRANSFER_HOOK_EXECUTE));
+
+    leg
+}
+
+/// 接收方从唯一一条转入获得的到账数量
+/// 交易中还有其它转账进出该账户、没有余额记录 (如临时账户) 或余额未增加时返回 None
+fn credited_amount(
+    meta: &TransactionStatusMeta,
+    account_keys: &AccountKeys,
+    instructions: &[FlattenedInstruction],
+    account_index: u32,
+) -> Option<u64> {
+    let transfers = instructions
+        .iter()
+        .filter_map(|inst| {
+            let program_id = account_keys.get(inst.program_id_index as usize)?;
+            parse_token_transfer(program_id, inst.accounts, inst.data)
+        })
+        .filter(|transfer| {
+            transfer.source == account_index || transfer.destination == account_index
+        })
+        .count();
+    if transfers != 1 {
+        return None;
+    }
+
+    received_amount(meta, account_index)
+}
+
+/// 账户的余额增量，没有余额记录 (如临时账户) 或未增加时返回 None
+fn received_amount(meta: &TransactionStatusMeta, account_index: u32) -> Option<u64> {
+    let post = meta
+        .post_token_balances
+        .iter()
+        .find(|b| b.account_index == account_index)?;
+    let pre = meta
+        .pre_token_balances
+        .iter()
+        .find(|b| b.account_index == account_index);
+
+    let change = token_amount(Some(post)) - token_amount(pre);
+    u64::try_from(change).ok().filter(|&received| received > 0)
+}
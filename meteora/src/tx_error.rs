@@ -0,0 +1,190 @@
+use crate::constant::{
+    ANCHOR_ERRORS, DAMM_V2_ERRORS, DBC_ERRORS, DLMM_ERRORS, INSTRUCTION_ERRORS,
+    INSTRUCTION_ERROR_CUSTOM, METEORA_DAMM_V2_PROGRAM_ID, METEORA_DBC_PROGRAM_ID,
+    METEORA_DLMM_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_ERRORS, TOKEN_PROGRAM_ID,
+    TRANSACTION_ERRORS, TRANSACTION_ERROR_INSTRUCTION,
+};
+
+/// 解码后的交易错误
+#[derive(Default)]
+pub struct DecodedError {
+    /// TransactionError 变体名，如 "InstructionError"
+    pub transaction_error: String,
+    /// 出错的外层指令序号 (仅 InstructionError)
+    pub instruction_index: Option<u32>,
+    /// InstructionError 变体名，如 "Custom" / "ComputationalBudgetExceeded"
+    pub instruction_error: String,
+    /// InstructionError::Custom 的错误码
+    pub custom_code: Option<u32>,
+}
+
+/// 解码 bincode 序列化的 TransactionError
+/// 布局: 变体序号 (u32)；InstructionError 后接 指令序号 (u8) + InstructionError 变体序号 (u32)，Custom 再接 错误码 (u32)
+pub fn decode_transaction_error(bytes: &[u8]) -> DecodedError {
+    let read_u32 = |offset: usize| -> Option<u32> {
+        Some(u32::from_le_bytes(
+            bytes.get(offset..offset + 4)?.try_into().ok()?,
+        ))
+    };
+
+    let mut decoded = DecodedError::default();
+    let tag = match read_u32(0) {
+        Some(tag) => tag,
+        None => return decoded,
+    };
+    decoded.transaction_error = variant_name(TRANSACTION_ERRORS, tag);
+    if tag != TRANSACTION_ERROR_INSTRUCTION {
+        return decoded;
+    }
+
+    decoded.instruction_index = bytes.get(4).map(|&index| index as u32);
+    if let Some(instruction_tag) = read_u32(5) {
+        decoded.instruction_error = variant_name(INSTRUCTION_ERRORS, instruction_tag);
+        if instruction_tag == INSTRUCTION_ERROR_CUSTOM {
+            decoded.custom_code = read_u32(9);
+        }
+    }
+
+    decoded
+}
+
+/// 枚举序号对应的名称，超出已知范围时输出序号
+fn variant_name(names: &[&str], tag: u32) -> String {
+    match names.get(tag as usize) {
+        Some(name) => name.to_string(),
+        None => format!("Unknown({})", tag),
+    }
+}
+
+/// 从日志中找到最先报错的程序 (最内层)，返回 Base58 程序 ID
+/// 日志格式: "Program <id> failed: <reason>"，CPI 失败会逐层向外重复输出
+pub fn failed_program(log_messages: &[String]) -> Option<&str> {
+    log_messages.iter().find_map(|line| {
+        let rest = line.strip_prefix("Program ")?;
+        let (program_id, reason) = rest.split_once(' ')?;
+        reason.starts_with("failed:").then_some(program_id)
+    })
+}
+
+/// 按报错程序查找自定义错误码名称
+/// Meteora 程序先查自身错误表，再查 Anchor 框架错误表
+pub fn custom_error_name(program_id: &[u8], code: u32) -> Option<&'static str> {
+    let table: &[(u32, &str)] = if program_id == METEORA_DLMM_PROGRAM_ID.0.as_slice() {
+        DLMM_ERRORS
+    } else if program_id == METEORA_DAMM_V2_PROGRAM_ID.0.as_slice() {
+        DAMM_V2_ERRORS
+    } else if program_id == METEORA_DBC_PROGRAM_ID.0.as_slice() {
+        DBC_ERRORS
+    } else if program_id == TOKEN_PROGRAM_ID.0.as_slice()
+        || program_id == TOKEN_2022_PROGRAM_ID.0.as_slice()
+    {
+        return lookup(TOKEN_ERRORS, code);
+    } else {
+        return None;
+    };
+
+    lookup(table, code).or_else(|| lookup(ANCHOR_ERRORS, code))
+}
+
+fn lookup(table: &[(u32, &'static str)], code: u32) -> Option<&'static str> {
+    table
+        .iter()
+        .find(|(known, _)| *known == code)
+        .map(|(_, name)| *name)
+}
+
+/// 从 Anchor 错误日志中解析错误名 (错误表未收录时兜底)
+/// 日志格式: "Program log: AnchorError ... Error Code: <Name>. Error Number: <code>. ..."
+pub fn anchor_error_name(log_messages: &[String]) -> Option<&str> {
+    log_messages.iter().find_map(|line| {
+        let (_, rest) = line.split_once("AnchorError")?;
+        let (_, rest) = rest.split_once("Error Code: ")?;
+        rest.split_once('.').map(|(name, _)| name)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// bincode: TransactionError::InstructionError(index, InstructionError::<tag>[(code)])
+    fn instruction_error(index: u8, tag: u32, code: Option<u32>) -> Vec<u8> {
+        let mut bytes = TRANSACTION_ERROR_INSTRUCTION.to_le_bytes().to_vec();
+        bytes.push(index);
+        bytes.extend_from_slice(&tag.to_le_bytes());
+        if let Some(code) = code {
+            bytes.extend_from_slice(&code.to_le_bytes());
+        }
+        bytes
+    }
+
+    #[test]
+    fn decodes_custom_instruction_error() {
+        let decoded =
+            decode_transaction_error(&instruction_error(2, INSTRUCTION_ERROR_CUSTOM, Some(6003)));
+        assert_eq!(decoded.transaction_error, "InstructionError");
+        assert_eq!(decoded.instruction_index, Some(2));
+        assert_eq!(decoded.instruction_error, "Custom");
+        assert_eq!(decoded.custom_code, Some(6003));
+    }
+
+    #[test]
+    fn decodes_builtin_instruction_error() {
+        let decoded = decode_transaction_error(&instruction_error(0, 37, None));
+        assert_eq!(decoded.instruction_error, "ComputationalBudgetExceeded");
+        assert_eq!(decoded.custom_code, None);
+    }
+
+    #[test]
+    fn decodes_other_transaction_errors() {
+        let decoded = decode_transaction_error(&7u32.to_le_bytes());
+        assert_eq!(decoded.transaction_error, "BlockhashNotFound");
+        assert_eq!(decoded.instruction_index, None);
+
+        let decoded = decode_transaction_error(&999u32.to_le_bytes());
+        assert_eq!(decoded.transaction_error, "Unknown(999)");
+
+        let decoded = decode_transaction_error(&[1, 2]);
+        assert!(decoded.transaction_error.is_empty());
+    }
+
+    #[test]
+    fn maps_custom_codes_by_program() {
+        let dlmm = METEORA_DLMM_PROGRAM_ID.0.as_slice();
+        assert_eq!(
+            custom_error_name(dlmm, 6003),
+            Some("ExceededAmountSlippageTolerance")
+        );
+        // 程序自身错误表未收录时查 Anchor 框架错误
+        assert_eq!(custom_error_name(dlmm, 2000), Some("ConstraintMut"));
+        assert_eq!(
+            custom_error_name(TOKEN_PROGRAM_ID.0.as_slice(), 1),
+            Some("InsufficientFunds")
+        );
+        assert_eq!(custom_error_name(&[0u8; 32], 6003), None);
+    }
+
+    #[test]
+    fn finds_failed_program_and_anchor_error() {
+        let logs: Vec<String> = [
+            "Program LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo invoke [1]",
+            "Program log: AnchorError occurred. Error Code: ExceededAmountSlippageTolerance. Error Number: 6003. Error Message: Exceeded amount slippage tolerance.",
+            "Program LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo consumed 30000 of 200000 compute units",
+            "Program LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo failed: custom program error: 0x1773",
+            "Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 failed: custom program error: 0x1773",
+        ]
+        .iter()
+        .map(|line| line.to_string())
+        .collect();
+
+        assert_eq!(
+            failed_program(&logs),
+            Some("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo")
+        );
+        assert_eq!(
+            anchor_error_name(&logs),
+            Some("ExceededAmountSlippageTolerance")
+        );
+        assert_eq!(failed_program(&logs[..3]), None);
+    }
+}
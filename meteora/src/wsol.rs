@@ -0,0 +1,162 @@
+use crate::constant::{SYSTEM_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID, WSOL_MINT_ID};
+use dex_common::account::AccountKeys;
+use dex_common::instruction::FlattenedInstruction;
+use dex_common::system::parse_system_funding;
+use dex_common::transfer::parse_token_transfer;
+use substreams_solana::pb::sf::solana::r#type::v1::TransactionStatusMeta;
+
+/// SPL Token 指令: InitializeAccount / InitializeAccount2 / InitializeAccount3
+/// accounts: account (0), mint (1)
+const TOKEN_INITIALIZE_ACCOUNT: [u8; 3] = [1, 16, 18];
+
+/// SPL Token 指令: CloseAccount
+/// accounts: account (0), destination (1), owner (2)
+const TOKEN_CLOSE_ACCOUNT: u8 = 9;
+
+/// 免租押金: (账户元数据 128 字节 + space) * 每字节每年 3480 lamports * 2 年
+fn rent_exempt_minimum(space: u64) -> u64 {
+    (128 + space).saturating_mul(3480 * 2)
+}
+
+/// 交易内创建、注资、使用后关闭的临时 WSOL 账户
+/// 这类账户不出现在 pre/post token balances 中，交易前后 lamports 均为 0
+pub struct TemporaryWsol {
+    pub account_index: u32,
+    /// 创建与转入的 lamports 总和 (含免租押金)
+    funded: u64,
+    /// 免租押金
+    rent: u64,
+}
+
+impl TemporaryWsol {
+    /// 注资中可用于 Swap 的 SOL 数量 (扣除免租押金)
+    pub fn wrapped_amount(&self) -> u64 {
+        self.funded.saturating_sub(self.rent)
+    }
+}
+
+/// 识别交易中所有临时 WSOL 账户并统计其 lamports 注资
+pub fn find_temporary_wsol_accounts(
+    meta: &TransactionStatusMeta,
+    account_keys: &AccountKeys,
+    instructions: &[FlattenedInstruction],
+) -> Vec<TemporaryWsol> {
+    let mut accounts: Vec<TemporaryWsol> = Vec::new();
+    let mut closed: Vec<u32> = Vec::new();
+
+    // 1. 以 WSOL 为 mint 初始化、且随后被关闭的 Token 账户
+    for inst in instructions {
+        let program_id = match account_keys.get(inst.program_id_index as usize) {
+            Some(id) => id,
+            None => continue,
+        };
+        if program_id != TOKEN_PROGRAM_ID.0.as_slice()
+            && program_id != TOKEN_2022_PROGRAM_ID.0.as_slice()
+        {
+            continue;
+        }
+
+        let account_index = match inst.accounts.first() {
+            Some(&index) => index as u32,
+            None => continue,
+        };
+
+        match inst.data.first() {
+            Some(tag) if TOKEN_INITIALIZE_ACCOUNT.contains(tag) => {
+                let is_wsol = inst
+                    .accounts
+                    .get(1)
+                    .and_then(|&mint| account_keys.get(mint as usize))
+                    .is_some_and(|mint| mint == WSOL_MINT_ID.0.as_slice());
+                if is_wsol && accounts.iter().all(|a| a.account_index != account_index) {
+                    accounts.push(TemporaryWsol {
+                        account_index,
+                        funded: 0,
+                        rent: 0,
+                    });
+                }
+            }
+            Some(&TOKEN_CLOSE_ACCOUNT) => closed.push(account_index),
+            _ => {}
+        }
+    }
+
+    // 交易前后 lamports 均为 0，且不在 token balances 中 (交易外不存在)
+    accounts.retain(|account| {
+        let index = account.account_index;
+        closed.contains(&index)
+            && meta.pre_balances.get(index as usize).copied().unwrap_or(0) == 0
+            && meta.post_balances.get(index as usize).copied().unwrap_or(0) == 0
+            && !meta
+                .pre_token_balances
+                .iter()
+                .chain(meta.post_token_balances.iter())
+                .any(|b| b.account_index == index)
+    });
+    if accounts.is_empty() {
+        return accounts;
+    }
+
+    // 2. 统计 System Program 对这些账户的注资 (CreateAccount / Transfer)
+    for inst in instructions {
+        let is_system = account_keys
+            .get(inst.program_id_index as usize)
+            .is_some_and(|id| id == SYSTEM_PROGRAM_ID.0.as_slice());
+        if !is_system {
+            continue;
+        }
+
+        let (recipient, lamports, space) = match parse_system_funding(inst.data) {
+            Some(funding) => (inst.accounts.get(1), funding.0, funding.1),
+            None => continue,
+        };
+        let account = match recipient.and_then(|&index| {
+            accounts
+                .iter_mut()
+                .find(|a| a.account_index == index as u32)
+        }) {
+            Some(account) => account,
+            None => continue,
+        };
+
+        account.funded = account.funded.saturating_add(lamports);
+        if let Some(space) = space {
+            account.rent = rent_exempt_minimum(space);
+        }
+    }
+
+    accounts
+}
+
+/// Swap 子指令中流出 (is_source) 或流入临时 WSOL 账户的 Token 转账总额
+/// 没有对应转账 (如旧区块缺失内层指令) 时返回 None
+pub fn swap_transfer_amount(
+    account_keys: &AccountKeys,
+    children: &[FlattenedInstruction],
+    account_index: u32,
+    is_source: bool,
+) -> Option<u64> {
+    let mut total: Option<u64> = None;
+
+    for child in children {
+        let program_id = match account_keys.get(child.program_id_index as usize) {
+            Some(id) => id,
+            None => continue,
+        };
+        let transfer = match parse_token_transfer(program_id, child.accounts, child.data) {
+            Some(t) => t,
+            None => continue,
+        };
+
+        let matched = if is_source {
+            transfer.source == account_index
+        } else {
+            transfer.destination == account_index
+        };
+        if matched {
+            total = Some(total.unwrap_or(0).checked_add(transfer.amount)?);
+        }
+    }
+
+    total
+}
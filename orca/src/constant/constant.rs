@@ -0,0 +1,37 @@
+use substreams_solana::b58;
+use substreams_solana_utils::pubkey::Pubkey;
+
+// ============================================================================
+// Orca Program IDs
+// ============================================================================
+
+/// Whirlpool - 集中流动性做市
+pub const ORCA_WHIRLPOOL_PROGRAM_ID: Pubkey =
+    Pubkey(b58!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc"));
+
+/// 程序 ID 与平台名称映射表
+pub const FILTER_PROGRAM_IDS: &[(Pubkey, &str)] = &[(ORCA_WHIRLPOOL_PROGRAM_ID, "orca_whirlpool")];
+
+// ============================================================================
+// Whirlpool Swap Discriminators (whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc)
+// ============================================================================
+
+/// swap - 基础 Swap 方法 (仅支持 SPL Token)
+/// args: amount (u64), other_amount_threshold (u64), sqrt_price_limit (u128),
+///       amount_specified_is_input (bool), a_to_b (bool)
+/// Pool 地址: accounts[2] (whirlpool)
+/// 池子金库: token_vault_a (4), token_vault_b (6)
+pub const WHIRLPOOL_SWAP: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+
+/// swap_v2 - 支持 Token-2022 的 Swap
+/// args: 同 swap, 外加 remaining_accounts_info
+/// Pool 地址: accounts[4] (whirlpool)
+/// 池子金库: token_vault_a (8), token_vault_b (10)
+pub const WHIRLPOOL_SWAP_V2: [u8; 8] = [43, 4, 237, 11, 26, 201, 30, 98];
+
+/// two_hop_swap - 单条指令内连续经过两个池子
+/// args: amount (u64), other_amount_threshold (u64), amount_specified_is_input (bool),
+///       a_to_b_one (bool), a_to_b_two (bool), sqrt_price_limit_one (u128), sqrt_price_limit_two (u128)
+/// Pool 地址: whirlpool_one (2), whirlpool_two (3)
+/// 池子金库: 第一跳 token_vault_one_a (5) / token_vault_one_b (7)，第二跳 token_vault_two_a (9) / token_vault_two_b (11)
+pub const WHIRLPOOL_TWO_HOP_SWAP: [u8; 8] = [195, 96, 237, 108, 68, 162, 219, 230];
@@ -0,0 +1,10 @@
+pub mod constant;
+
+pub use constant::{
+    // Program IDs
+    FILTER_PROGRAM_IDS,
+    // Whirlpool Discriminators
+    WHIRLPOOL_SWAP,
+    WHIRLPOOL_SWAP_V2,
+    WHIRLPOOL_TWO_HOP_SWAP,
+};
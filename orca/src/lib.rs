@@ -0,0 +1,232 @@
+mod constant;
+#[allow(unused)]
+mod pb;
+
+use crate::constant::{
+    FILTER_PROGRAM_IDS, WHIRLPOOL_SWAP, WHIRLPOOL_SWAP_V2, WHIRLPOOL_TWO_HOP_SWAP,
+};
+use dex_common::account::AccountKeys;
+use dex_common::balance::account_owner;
+use dex_common::instruction::{flatten_instructions, get_child_instructions};
+use dex_common::swap::vault_swap;
+use pb::orca::{SwapEvent, SwapEvents, SwapSide};
+use substreams_solana::pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction};
+
+// ============================================================================
+// 主 Map Handler
+// ============================================================================
+#[substreams::handlers::map]
+fn orca(block: Block) -> SwapEvents {
+    let mut swap_events = SwapEvents::default();
+    let slot = block.slot;
+    let timestamp_ms = block
+        .block_time
+        .as_ref()
+        .map(|t| t.timestamp as u64 * 1000)
+        .unwrap_or(0);
+
+    for (tx_index, tx) in block.transactions.iter().enumerate() {
+        // 跳过失败的交易
+        if let Some(ref meta) = tx.meta {
+            if meta.err.is_some() {
+                continue;
+            }
+        } else {
+            continue;
+        }
+
+        // 解析交易中的 Swap 事件
+        if let Some(events) = parse_transaction(tx, slot, timestamp_ms, tx_index as u32) {
+            swap_events.swaps.extend(events);
+        }
+    }
+
+    swap_events
+}
+
+// ============================================================================
+// 解析单笔交易
+// ============================================================================
+fn parse_transaction(
+    tx: &ConfirmedTransaction,
+    slot: u64,
+    timestamp_ms: u64,
+    tx_index: u32,
+) -> Option<Vec<SwapEvent>> {
+    let transaction = tx.transaction.as_ref()?;
+    let message = transaction.message.as_ref()?;
+    let meta = tx.meta.as_ref()?;
+
+    // 获取交易签名
+    let signature = bs58::encode(transaction.signatures.first()?).into_string();
+
+    // 获取完整账户列表 (包含 ALT 补充地址)
+    let account_keys = AccountKeys::new(message, meta);
+
+    // 获取发起者 (第一个签名者)
+    let signer_str = bs58::encode(account_keys.get(0)?).into_string();
+
+    let instructions = flatten_instructions(message, meta);
+    let mut swap_events = Vec::new();
+
+    // 遍历所有指令 (包括内部指令)
+    for (position, inst) in instructions.iter().enumerate() {
+        // 获取程序 ID，如果索引越界则跳过
+        let program_id = match account_keys.get(inst.program_id_index as usize) {
+            Some(id) => id,
+            None => continue,
+        };
+
+        // 检查是否是目标程序，不是则跳过
+        let platform = match match_program(program_id) {
+            Some(p) => p,
+            None => continue,
+        };
+
+        // 匹配 Swap 指令并定位每一跳的 Pool / 金库账户
+        let hops = get_swap_hops(platform, inst.data);
+        if hops.is_empty() {
+            continue;
+        }
+        let children = get_child_instructions(&instructions, position);
+
+        for (hop_index, hop) in hops.iter().enumerate() {
+            // Pool 或金额缺失时跳过，不产出占位事件
+            let pool = match inst
+                .accounts
+                .get(hop.pool)
+                .and_then(|&idx| account_keys.get(idx as usize))
+            {
+                Some(key) => bs58::encode(key).into_string(),
+                None => continue,
+            };
+
+            let vaults = match (
+                inst.accounts.get(hop.vault_a),
+                inst.accounts.get(hop.vault_b),
+            ) {
+                (Some(&a), Some(&b)) => [a as u32, b as u32],
+                _ => continue,
+            };
+
+            // 从 Swap 内部的 Token 转账计算金额 (每一跳只统计进出本池金库的转账)
+            let swap = match vault_swap(meta, &account_keys, children, vaults) {
+                Some(swap) => swap,
+                None => continue,
+            };
+            // 真实交易者: 用户 Token 账户的 owner (临时 WSOL 账户可能没有余额记录，依次尝试两侧)
+            let trader = [hop.owner_a, hop.owner_b]
+                .iter()
+                .filter_map(|&position| inst.accounts.get(position))
+                .find_map(|&index| account_owner(meta, index as u32))
+                .map(str::to_string)
+                .unwrap_or_else(|| signer_str.clone());
+
+            let side = if swap.is_buy {
+                SwapSide::SideBuy
+            } else {
+                SwapSide::SideSell
+            };
+
+            // two_hop_swap 的两跳属于同一条指令: 第一跳沿用指令自身的位置，
+            // 之后的每一跳使用其第一笔金库转账的内层序号，保证 (instruction_index, inner_instruction_index) 唯一
+            let inner_instruction_index = if hop_index == 0 {
+                inst.inner_instruction_index
+            } else {
+                swap.first_transfer_index
+            };
+
+            swap_events.push(SwapEvent {
+                pool,
+                signature: signature.clone(),
+                user: signer_str.clone(),
+                platform: platform.to_string(),
+                timestamp_ms,
+                slot,
+                tx_index,
+                base_mint: swap.base_mint,
+                quote_mint: swap.quote_mint,
+                base_amount: swap.base_amount,
+                quote_amount: swap.quote_amount,
+                side: side.into(),
+                base_decimals: swap.base_decimals,
+                quote_decimals: swap.quote_decimals,
+                instruction_index: inst.instruction_index,
+                inner_instruction_index,
+                trader,
+            });
+        }
+    }
+
+    if swap_events.is_empty() {
+        None
+    } else {
+        Some(swap_events)
+    }
+}
+
+// ============================================================================
+// 辅助函数
+// ============================================================================
+
+/// 单跳 Swap 的关键账户在 accounts 中的位置
+struct SwapHop {
+    pool: usize,
+    vault_a: usize,
+    vault_b: usize,
+    /// 用户的 token_a / token_b 账户，其 owner 即真实交易者
+    owner_a: usize,
+    owner_b: usize,
+}
+
+/// 匹配程序 ID，返回平台名称
+fn match_program(program_id: &[u8]) -> Option<&'static str> {
+    for (target_id, platform_name) in FILTER_PROGRAM_IDS.iter() {
+        if target_id.0.as_slice() == program_id {
+            return Some(*platform_name);
+        }
+    }
+    None
+}
+
+/// 识别 Swap 指令，返回每一跳的 Pool 与池子金库在 accounts 中的位置 (根据 IDL)
+/// 非 Swap 指令返回空列表
+fn get_swap_hops(platform: &str, data: &[u8]) -> Vec<SwapHop> {
+    if platform != "orca_whirlpool" {
+        return Vec::new();
+    }
+
+    match data.get(0..8) {
+        Some(d) if d == WHIRLPOOL_SWAP => vec![SwapHop {
+            pool: 2,
+            vault_a: 4,
+            vault_b: 6,
+            owner_a: 3,
+            owner_b: 5,
+        }],
+        Some(d) if d == WHIRLPOOL_SWAP_V2 => vec![SwapHop {
+            pool: 4,
+            vault_a: 8,
+            vault_b: 10,
+            owner_a: 7,
+            owner_b: 9,
+        }],
+        Some(d) if d == WHIRLPOOL_TWO_HOP_SWAP => vec![
+            SwapHop {
+                pool: 2,
+                vault_a: 5,
+                vault_b: 7,
+                owner_a: 4,
+                owner_b: 6,
+            },
+            SwapHop {
+                pool: 3,
+                vault_a: 9,
+                vault_b: 11,
+                owner_a: 8,
+                owner_b: 10,
+            },
+        ],
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,9 @@
+// @generated
+// This file is @generated by prost-build.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Transactions {
+    #[prost(message, repeated, tag="1")]
+    pub transactions: ::prost::alloc::vec::Vec<super::super::super::solana::r#type::v1::ConfirmedTransaction>,
+}
+// @@protoc_insertion_point(module)
@@ -0,0 +1,39 @@
+use substreams_solana::b58;
+use substreams_solana_utils::pubkey::Pubkey;
+
+// ============================================================================
+// Pump.fun Program IDs
+// ============================================================================
+
+/// Pump.fun Bonding Curve
+pub const PUMPFUN_PROGRAM_ID: Pubkey = Pubkey(b58!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P"));
+
+/// 程序 ID 与平台名称映射表
+pub const FILTER_PROGRAM_IDS: &[(Pubkey, &str)] = &[(PUMPFUN_PROGRAM_ID, "pumpfun")];
+
+// ============================================================================
+// Pump.fun Swap Discriminators (6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P)
+// Pool 地址: accounts[3] (bonding_curve)
+// accounts[2] 是 mint, accounts[5] 是 associated_user, accounts[6] 是 user
+// ============================================================================
+
+/// buy - 用 SOL 买入代币
+/// args: amount (u64), max_sol_cost (u64)
+pub const PUMPFUN_BUY: [u8; 8] = [102, 6, 61, 18, 1, 218, 235, 234];
+
+/// sell - 卖出代币换 SOL
+/// args: amount (u64), min_sol_output (u64)
+pub const PUMPFUN_SELL: [u8; 8] = [51, 230, 133, 164, 1, 127, 131, 173];
+
+// ============================================================================
+// Anchor CPI 事件 (emit_cpi!)
+// 程序通过 self-CPI 输出事件: data = EVENT_IX_TAG (8) + 事件 discriminator (8) + Borsh 字段
+// ============================================================================
+
+/// Anchor 事件指令前缀 (0x1d9acb512ea545e4, 小端序)
+pub const ANCHOR_EVENT_IX_TAG: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+
+/// TradeEvent - 每笔 buy/sell 的精确成交结果
+/// fields: mint (Pubkey), sol_amount (u64), token_amount (u64), is_buy (bool), user (Pubkey), timestamp (i64),
+///         virtual_sol_reserves (u64), virtual_token_reserves (u64), ...
+pub const TRADE_EVENT: [u8; 8] = [189, 219, 127, 211, 78, 230, 97, 238];
@@ -0,0 +1,12 @@
+pub mod constant;
+
+pub use constant::{
+    // Anchor Events
+    ANCHOR_EVENT_IX_TAG,
+    // Program IDs
+    FILTER_PROGRAM_IDS,
+    // Discriminators
+    PUMPFUN_BUY,
+    PUMPFUN_SELL,
+    TRADE_EVENT,
+};
@@ -1,12 +1,231 @@
+mod constant;
 #[allow(unused)]
 mod pb;
-use pb::{mydata::v1 as mydata, sf::substreams::solana::v1::Transactions};
 
+use crate::constant::{
+    ANCHOR_EVENT_IX_TAG, FILTER_PROGRAM_IDS, PUMPFUN_BUY, PUMPFUN_SELL, TRADE_EVENT,
+};
+use dex_common::account::AccountKeys;
+use dex_common::constant::WSOL_MINT;
+use dex_common::instruction::{flatten_instructions, get_child_instructions, FlattenedInstruction};
+use dex_common::price::reserve_price;
+use pb::pumpfun::{SwapEvent, SwapEvents, SwapSide};
+use substreams_solana::pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction};
+
+/// Pump.fun 代币统一为 6 位精度
+const PUMPFUN_TOKEN_DECIMALS: u32 = 6;
+
+/// SOL 精度
+const SOL_DECIMALS: u32 = 9;
+
+// ============================================================================
+// 主 Map Handler
+// ============================================================================
 #[substreams::handlers::map]
-fn map_my_data(transactions: Transactions) -> mydata::MyData {
-    // TODO: Modify this code to get the data that you need from the transactions.
+fn pumpfun(block: Block) -> SwapEvents {
+    let mut swap_events = SwapEvents::default();
+    let slot = block.slot;
+    let timestamp_ms = block
+        .block_time
+        .as_ref()
+        .map(|t| t.timestamp as u64 * 1000)
+        .unwrap_or(0);
+
+    for (tx_index, tx) in block.transactions.iter().enumerate() {
+        // 跳过失败的交易
+        if let Some(ref meta) = tx.meta {
+            if meta.err.is_some() {
+                continue;
+            }
+        } else {
+            continue;
+        }
+
+        // 解析交易中的 Swap 事件
+        if let Some(events) = parse_transaction(tx, slot, timestamp_ms, tx_index as u32) {
+            swap_events.swaps.extend(events);
+        }
+    }
+
+    swap_events
+}
+
+// ============================================================================
+// 解析单笔交易
+// ============================================================================
+fn parse_transaction(
+    tx: &ConfirmedTransaction,
+    slot: u64,
+    timestamp_ms: u64,
+    tx_index: u32,
+) -> Option<Vec<SwapEvent>> {
+    let transaction = tx.transaction.as_ref()?;
+    let message = transaction.message.as_ref()?;
+    let meta = tx.meta.as_ref()?;
+
+    // 获取交易签名
+    let signature = bs58::encode(transaction.signatures.first()?).into_string();
+
+    // 获取完整账户列表 (包含 ALT 补充地址)
+    let account_keys = AccountKeys::new(message, meta);
+
+    // 获取发起者 (第一个签名者)
+    let signer_str = bs58::encode(account_keys.get(0)?).into_string();
+
+    let instructions = flatten_instructions(message, meta);
+    let mut swap_events = Vec::new();
+
+    // 遍历所有指令 (包括内部指令)
+    for (position, inst) in instructions.iter().enumerate() {
+        // 获取程序 ID，如果索引越界则跳过
+        let program_id = match account_keys.get(inst.program_id_index as usize) {
+            Some(id) => id,
+            None => continue,
+        };
+
+        // 检查是否是目标程序，不是则跳过
+        let platform = match match_program(program_id) {
+            Some(p) => p,
+            None => continue,
+        };
+
+        // 只处理 buy / sell
+        match inst.data.get(0..8) {
+            Some(d) if d == PUMPFUN_BUY || d == PUMPFUN_SELL => {}
+            _ => continue,
+        }
+
+        // 提取 Bonding Curve 地址 (accounts[3])，缺失时跳过
+        let pool = match inst
+            .accounts
+            .get(3)
+            .and_then(|&idx| account_keys.get(idx as usize))
+        {
+            Some(key) => bs58::encode(key).into_string(),
+            None => continue,
+        };
+
+        // 从 buy/sell 发出的 TradeEvent 中读取精确成交金额，找不到事件时不产出占位事件
+        let children = get_child_instructions(&instructions, position);
+        let trade = match find_trade_event(program_id, &account_keys, children) {
+            Some(t) => t,
+            None => continue,
+        };
+        let side = if trade.is_buy {
+            SwapSide::SideBuy
+        } else {
+            SwapSide::SideSell
+        };
+
+        swap_events.push(SwapEvent {
+            pool,
+            signature: signature.clone(),
+            user: signer_str.clone(),
+            platform: platform.to_string(),
+            timestamp_ms,
+            slot,
+            tx_index,
+            base_mint: bs58::encode(trade.mint).into_string(),
+            quote_mint: WSOL_MINT.to_string(),
+            base_amount: trade.token_amount,
+            quote_amount: trade.sol_amount,
+            side: side.into(),
+            base_decimals: PUMPFUN_TOKEN_DECIMALS,
+            quote_decimals: SOL_DECIMALS,
+            instruction_index: inst.instruction_index,
+            inner_instruction_index: inst.inner_instruction_index,
+            trader: bs58::encode(trade.user).into_string(),
+            price_before: trade.price_before(),
+        });
+    }
+
+    if swap_events.is_empty() {
+        None
+    } else {
+        Some(swap_events)
+    }
+}
+
+// ============================================================================
+// 辅助函数
+// ============================================================================
+
+/// TradeEvent 中用到的字段
+struct TradeEvent {
+    mint: [u8; 32],
+    sol_amount: u64,
+    token_amount: u64,
+    is_buy: bool,
+    user: [u8; 32],
+    /// 成交后的虚拟储备
+    virtual_sol_reserves: u64,
+    virtual_token_reserves: u64,
+}
+
+impl TradeEvent {
+    /// 成交前的曲线价格 (SOL / token，按精度换算): 由成交后的虚拟储备回退本笔金额得到
+    /// buy 时 SOL 储备增加、token 储备减少，sell 相反；回退失败时为 0
+    fn price_before(&self) -> f64 {
+        let (sol_before, token_before) = if self.is_buy {
+            (
+                self.virtual_sol_reserves.checked_sub(self.sol_amount),
+                self.virtual_token_reserves.checked_add(self.token_amount),
+            )
+        } else {
+            (
+                self.virtual_sol_reserves.checked_add(self.sol_amount),
+                self.virtual_token_reserves.checked_sub(self.token_amount),
+            )
+        };
+        match (sol_before, token_before) {
+            (Some(sol), Some(token)) => {
+                reserve_price(token, sol, PUMPFUN_TOKEN_DECIMALS, SOL_DECIMALS)
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+/// 匹配程序 ID，返回平台名称
+fn match_program(program_id: &[u8]) -> Option<&'static str> {
+    for (target_id, platform_name) in FILTER_PROGRAM_IDS.iter() {
+        if target_id.0.as_slice() == program_id {
+            return Some(*platform_name);
+        }
+    }
+    None
+}
+
+/// 在 buy/sell 的子指令中查找 Pump.fun 通过 self-CPI 发出的 TradeEvent
+fn find_trade_event(
+    program_id: &[u8],
+    account_keys: &AccountKeys,
+    children: &[FlattenedInstruction],
+) -> Option<TradeEvent> {
+    children
+        .iter()
+        .filter(|child| {
+            account_keys
+                .get(child.program_id_index as usize)
+                .is_some_and(|id| id == program_id)
+        })
+        .find_map(|child| parse_trade_event(child.data))
+}
+
+/// 解析 TradeEvent: EVENT_IX_TAG (8) + discriminator (8) + mint (32) + sol_amount (8) + token_amount (8) + is_buy (1) + user (32)
+///                  + timestamp (8) + virtual_sol_reserves (8) + virtual_token_reserves (8)
+fn parse_trade_event(data: &[u8]) -> Option<TradeEvent> {
+    if data.get(0..8)? != ANCHOR_EVENT_IX_TAG || data.get(8..16)? != TRADE_EVENT {
+        return None;
+    }
 
-    let mut my_data = mydata::MyData::default();
-    my_data.transactions = transactions.transactions;
-    my_data
+    Some(TradeEvent {
+        mint: data.get(16..48)?.try_into().ok()?,
+        sol_amount: u64::from_le_bytes(data.get(48..56)?.try_into().ok()?),
+        token_amount: u64::from_le_bytes(data.get(56..64)?.try_into().ok()?),
+        is_buy: *data.get(64)? != 0,
+        user: data.get(65..97)?.try_into().ok()?,
+        virtual_sol_reserves: u64::from_le_bytes(data.get(105..113)?.try_into().ok()?),
+        virtual_token_reserves: u64::from_le_bytes(data.get(113..121)?.try_into().ok()?),
+    })
 }
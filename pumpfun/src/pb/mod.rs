@@ -1,10 +1,8 @@
 // @generated
-pub mod mydata {
-    // @@protoc_insertion_point(attribute:mydata.v1)
-    pub mod v1 {
-        include!("mydata.v1.rs");
-        // @@protoc_insertion_point(mydata.v1)
-    }
+// @@protoc_insertion_point(attribute:pumpfun)
+pub mod pumpfun {
+    include!("pumpfun.rs");
+    // @@protoc_insertion_point(pumpfun)
 }
 pub mod sf {
     pub mod solana {
@@ -0,0 +1,76 @@
+use substreams_solana::b58;
+use substreams_solana_utils::pubkey::Pubkey;
+
+// ============================================================================
+// Raydium Program IDs
+// ============================================================================
+
+/// AMM V4 - 经典恒定乘积 AMM (OpenBook 订单簿混合)
+pub const RAYDIUM_AMM_V4_PROGRAM_ID: Pubkey =
+    Pubkey(b58!("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8"));
+
+/// CPMM - 标准恒定乘积 AMM (支持 Token-2022)
+pub const RAYDIUM_CPMM_PROGRAM_ID: Pubkey =
+    Pubkey(b58!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C"));
+
+/// CLMM - 集中流动性做市
+pub const RAYDIUM_CLMM_PROGRAM_ID: Pubkey =
+    Pubkey(b58!("CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"));
+
+/// 程序 ID 与平台名称映射表
+pub const FILTER_PROGRAM_IDS: &[(Pubkey, &str)] = &[
+    (RAYDIUM_AMM_V4_PROGRAM_ID, "raydium_amm"),
+    (RAYDIUM_CPMM_PROGRAM_ID, "raydium_cpmm"),
+    (RAYDIUM_CLMM_PROGRAM_ID, "raydium_clmm"),
+];
+
+// ============================================================================
+// AMM V4 Swap Instructions (675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8)
+// 非 Anchor 程序，指令类型为 data[0]
+// Pool 地址: accounts[1] (amm)
+// 用户账户位于 accounts 末尾: user_source (len-3), user_destination (len-2), user_owner (len-1)
+// ============================================================================
+
+/// swap_base_in - 指定输入金额
+/// args: amount_in (u64), minimum_amount_out (u64)
+pub const AMM_V4_SWAP_BASE_IN: u8 = 9;
+
+/// swap_base_out - 指定输出金额
+/// args: max_amount_in (u64), amount_out (u64)
+pub const AMM_V4_SWAP_BASE_OUT: u8 = 11;
+
+/// swap_base_in_v2 - 去除 OpenBook 账户的精简版 (8 个账户)
+pub const AMM_V4_SWAP_BASE_IN_V2: u8 = 16;
+
+/// swap_base_out_v2 - 去除 OpenBook 账户的精简版 (8 个账户)
+pub const AMM_V4_SWAP_BASE_OUT_V2: u8 = 17;
+
+// ============================================================================
+// CPMM Swap Discriminators (CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C)
+// Pool 地址: accounts[3] (pool_state)
+// 用户账户: input_token_account (4), output_token_account (5)
+// 池子金库: input_vault (6), output_vault (7)
+// ============================================================================
+
+/// swap_base_input - 指定输入金额
+/// args: amount_in (u64), minimum_amount_out (u64)
+pub const CPMM_SWAP_BASE_INPUT: [u8; 8] = [143, 190, 90, 218, 196, 30, 51, 222];
+
+/// swap_base_output - 指定输出金额
+/// args: max_amount_in (u64), amount_out (u64)
+pub const CPMM_SWAP_BASE_OUTPUT: [u8; 8] = [55, 217, 98, 86, 163, 74, 180, 173];
+
+// ============================================================================
+// CLMM Swap Discriminators (CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK)
+// Pool 地址: accounts[2] (pool_state)
+// 用户账户: input_token_account (3), output_token_account (4)
+// 池子金库: input_vault (5), output_vault (6)
+// ============================================================================
+
+/// swap - 基础 Swap 方法 (仅支持 SPL Token)
+/// args: amount (u64), other_amount_threshold (u64), sqrt_price_limit_x64 (u128), is_base_input (bool)
+pub const CLMM_SWAP: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+
+/// swap_v2 - 支持 Token-2022 的 Swap
+/// args: 同 swap
+pub const CLMM_SWAP_V2: [u8; 8] = [43, 4, 237, 11, 26, 201, 30, 98];
@@ -0,0 +1,17 @@
+pub mod constant;
+
+pub use constant::{
+    // AMM V4 Instructions
+    AMM_V4_SWAP_BASE_IN,
+    AMM_V4_SWAP_BASE_IN_V2,
+    AMM_V4_SWAP_BASE_OUT,
+    AMM_V4_SWAP_BASE_OUT_V2,
+    // CLMM Discriminators
+    CLMM_SWAP,
+    CLMM_SWAP_V2,
+    // CPMM Discriminators
+    CPMM_SWAP_BASE_INPUT,
+    CPMM_SWAP_BASE_OUTPUT,
+    // Program IDs
+    FILTER_PROGRAM_IDS,
+};
@@ -0,0 +1,252 @@
+mod constant;
+#[allow(unused)]
+mod pb;
+
+use crate::constant::{
+    AMM_V4_SWAP_BASE_IN, AMM_V4_SWAP_BASE_IN_V2, AMM_V4_SWAP_BASE_OUT, AMM_V4_SWAP_BASE_OUT_V2,
+    CLMM_SWAP, CLMM_SWAP_V2, CPMM_SWAP_BASE_INPUT, CPMM_SWAP_BASE_OUTPUT, FILTER_PROGRAM_IDS,
+};
+use dex_common::account::AccountKeys;
+use dex_common::balance::account_owner;
+use dex_common::instruction::{flatten_instructions, get_child_instructions};
+use dex_common::price::reserve_price;
+use dex_common::swap::vault_swap;
+use pb::raydium::{SwapEvent, SwapEvents, SwapSide};
+use substreams_solana::pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction};
+
+// ============================================================================
+// 主 Map Handler
+// ============================================================================
+#[substreams::handlers::map]
+fn raydium(block: Block) -> SwapEvents {
+    let mut swap_events = SwapEvents::default();
+    let slot = block.slot;
+    let timestamp_ms = block
+        .block_time
+        .as_ref()
+        .map(|t| t.timestamp as u64 * 1000)
+        .unwrap_or(0);
+
+    for (tx_index, tx) in block.transactions.iter().enumerate() {
+        // 跳过失败的交易
+        if let Some(ref meta) = tx.meta {
+            if meta.err.is_some() {
+                continue;
+            }
+        } else {
+            continue;
+        }
+
+        // 解析交易中的 Swap 事件
+        if let Some(events) = parse_transaction(tx, slot, timestamp_ms, tx_index as u32) {
+            swap_events.swaps.extend(events);
+        }
+    }
+
+    swap_events
+}
+
+// ============================================================================
+// 解析单笔交易
+// ============================================================================
+fn parse_transaction(
+    tx: &ConfirmedTransaction,
+    slot: u64,
+    timestamp_ms: u64,
+    tx_index: u32,
+) -> Option<Vec<SwapEvent>> {
+    let transaction = tx.transaction.as_ref()?;
+    let message = transaction.message.as_ref()?;
+    let meta = tx.meta.as_ref()?;
+
+    // 获取交易签名
+    let signature = bs58::encode(transaction.signatures.first()?).into_string();
+
+    // 获取完整账户列表 (包含 ALT 补充地址)
+    let account_keys = AccountKeys::new(message, meta);
+
+    // 获取发起者 (第一个签名者)
+    let signer_str = bs58::encode(account_keys.get(0)?).into_string();
+
+    let instructions = flatten_instructions(message, meta);
+    let mut swap_events = Vec::new();
+
+    // 遍历所有指令 (包括内部指令)
+    for (position, inst) in instructions.iter().enumerate() {
+        // 获取程序 ID，如果索引越界则跳过
+        let program_id = match account_keys.get(inst.program_id_index as usize) {
+            Some(id) => id,
+            None => continue,
+        };
+
+        // 检查是否是目标程序，不是则跳过
+        let platform = match match_program(program_id) {
+            Some(p) => p,
+            None => continue,
+        };
+
+        // 匹配 Swap 指令并定位 Pool / 金库账户
+        let swap_accounts = match get_swap_accounts(platform, inst.data, inst.accounts.len()) {
+            Some(accounts) => accounts,
+            None => continue,
+        };
+
+        // Pool 或金额缺失时跳过，不产出占位事件
+        let pool = match inst
+            .accounts
+            .get(swap_accounts.pool)
+            .and_then(|&idx| account_keys.get(idx as usize))
+        {
+            Some(key) => bs58::encode(key).into_string(),
+            None => continue,
+        };
+
+        let vaults = match (
+            inst.accounts.get(swap_accounts.vault_a),
+            inst.accounts.get(swap_accounts.vault_b),
+        ) {
+            (Some(&a), Some(&b)) => [a as u32, b as u32],
+            _ => continue,
+        };
+
+        // 从 Swap 内部的 Token 转账计算金额
+        let children = get_child_instructions(&instructions, position);
+        let swap = match vault_swap(meta, &account_keys, children, vaults) {
+            Some(swap) => swap,
+            None => continue,
+        };
+        // 真实交易者: 用户 Token 账户的 owner (输入账户优先，临时 WSOL 账户可能没有余额记录)
+        let trader = [swap_accounts.user_source, swap_accounts.user_destination]
+            .iter()
+            .filter_map(|&position| inst.accounts.get(position))
+            .find_map(|&index| account_owner(meta, index as u32))
+            .map(str::to_string)
+            .unwrap_or_else(|| signer_str.clone());
+
+        // 成交前价格: AMM V4 / CPMM 为恒定乘积池，金库储备比即价格；CLMM 为集中流动性，无法由金库余额得到
+        let price_before = if platform == "raydium_clmm" {
+            0.0
+        } else {
+            reserve_price(
+                swap.base_reserve_before,
+                swap.quote_reserve_before,
+                swap.base_decimals,
+                swap.quote_decimals,
+            )
+        };
+
+        let side = if swap.is_buy {
+            SwapSide::SideBuy
+        } else {
+            SwapSide::SideSell
+        };
+
+        swap_events.push(SwapEvent {
+            pool,
+            signature: signature.clone(),
+            user: signer_str.clone(),
+            platform: platform.to_string(),
+            timestamp_ms,
+            slot,
+            tx_index,
+            base_mint: swap.base_mint,
+            quote_mint: swap.quote_mint,
+            base_amount: swap.base_amount,
+            quote_amount: swap.quote_amount,
+            side: side.into(),
+            base_decimals: swap.base_decimals,
+            quote_decimals: swap.quote_decimals,
+            instruction_index: inst.instruction_index,
+            inner_instruction_index: inst.inner_instruction_index,
+            trader,
+            price_before,
+        });
+    }
+
+    if swap_events.is_empty() {
+        None
+    } else {
+        Some(swap_events)
+    }
+}
+
+// ============================================================================
+// 辅助函数
+// ============================================================================
+
+/// Swap 指令中关键账户在 accounts 中的位置
+struct SwapAccounts {
+    pool: usize,
+    vault_a: usize,
+    vault_b: usize,
+    /// 用户付出 / 收到的 Token 账户，其 owner 即真实交易者
+    user_source: usize,
+    user_destination: usize,
+}
+
+/// 匹配程序 ID，返回平台名称
+fn match_program(program_id: &[u8]) -> Option<&'static str> {
+    for (target_id, platform_name) in FILTER_PROGRAM_IDS.iter() {
+        if target_id.0.as_slice() == program_id {
+            return Some(*platform_name);
+        }
+    }
+    None
+}
+
+/// 识别 Swap 指令，返回 Pool 与两个池子金库在 accounts 中的位置 (根据 IDL)
+fn get_swap_accounts(platform: &str, data: &[u8], num_accounts: usize) -> Option<SwapAccounts> {
+    match platform {
+        "raydium_amm" => {
+            let instruction = *data.first()?;
+            if instruction != AMM_V4_SWAP_BASE_IN
+                && instruction != AMM_V4_SWAP_BASE_OUT
+                && instruction != AMM_V4_SWAP_BASE_IN_V2
+                && instruction != AMM_V4_SWAP_BASE_OUT_V2
+            {
+                return None;
+            }
+            // 金库与用户账户位置随账户数量变化: 18 个 (含 target_orders) / 17 个 / 8 个 (v2 精简版)
+            let (vault_a, vault_b, user_source, user_destination) = match num_accounts {
+                18 => (5, 6, 15, 16),
+                17 => (4, 5, 14, 15),
+                8 => (3, 4, 5, 6),
+                _ => return None,
+            };
+            Some(SwapAccounts {
+                pool: 1,
+                vault_a,
+                vault_b,
+                user_source,
+                user_destination,
+            })
+        }
+        "raydium_cpmm" => {
+            let discriminator = data.get(0..8)?;
+            if discriminator != CPMM_SWAP_BASE_INPUT && discriminator != CPMM_SWAP_BASE_OUTPUT {
+                return None;
+            }
+            Some(SwapAccounts {
+                pool: 3,
+                vault_a: 6,
+                vault_b: 7,
+                user_source: 4,
+                user_destination: 5,
+            })
+        }
+        "raydium_clmm" => {
+            let discriminator = data.get(0..8)?;
+            if discriminator != CLMM_SWAP && discriminator != CLMM_SWAP_V2 {
+                return None;
+            }
+            Some(SwapAccounts {
+                pool: 2,
+                vault_a: 5,
+                vault_b: 6,
+                user_source: 3,
+                user_destination: 4,
+            })
+        }
+        _ => None,
+    }
+}